@@ -0,0 +1,288 @@
+use std::{collections::BTreeMap, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Result, Variant};
+
+/// Offline record of submitted answers, usually stored in `answers.toml`.
+///
+/// ```toml
+/// [day-14.first]
+/// accepted = 105784
+///
+/// [day-14.second]
+/// too-low = [90000]
+/// wrong = [95001, 95002]
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(flatten)]
+    days: BTreeMap<String, Day>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Day {
+    #[serde(default, skip_serializing_if = "Guesses::is_empty")]
+    first: Guesses,
+    #[serde(default, skip_serializing_if = "Guesses::is_empty")]
+    second: Guesses,
+}
+
+/// Everything known about the answer to a single part.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Guesses {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accepted: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<Answer>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub too_high: Vec<Answer>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub too_low: Vec<Answer>,
+}
+
+/// An answer, written as a number to the ledger whenever possible.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "RawAnswer", into = "RawAnswer")]
+pub struct Answer(pub String);
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum RawAnswer {
+    Number(i64),
+    Text(String),
+}
+
+/// The feedback received after submitting an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feedback {
+    Accepted,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+/// How a solution relates to the recorded answers.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict<'l> {
+    Accepted,
+    NotAccepted(&'l Answer),
+    KnownWrong,
+    TooHigh(&'l Answer),
+    TooLow(&'l Answer),
+    Unknown,
+}
+
+impl Ledger {
+    /// Load the ledger, which is empty if the file does not exist yet.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(raw) => Ok(toml::from_str(&raw)?),
+            Err(why) if why.kind() == std::io::ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(why) => Err(why.into()),
+        }
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result {
+        std::fs::write(path, toml::to_string(self)?)?;
+        Ok(())
+    }
+
+    pub fn guesses(&self, day: u8, variant: Variant) -> Option<&Guesses> {
+        let day = self.days.get(&key(day))?;
+        match variant {
+            Variant::First => Some(&day.first),
+            Variant::Second => Some(&day.second),
+            Variant::Both => None,
+        }
+    }
+
+    pub fn check<S: ToString>(&self, day: u8, variant: Variant, solution: &S) -> Verdict<'_> {
+        match self.guesses(day, variant) {
+            Some(guesses) => guesses.check(&Answer(solution.to_string())),
+            None => Verdict::Unknown,
+        }
+    }
+
+    pub fn record<S: ToString>(
+        &mut self,
+        day: u8,
+        variant: Variant,
+        feedback: Feedback,
+        solution: &S,
+    ) -> Result {
+        let day = self.days.entry(key(day)).or_default();
+        let guesses = match variant {
+            Variant::First => &mut day.first,
+            Variant::Second => &mut day.second,
+            Variant::Both => {
+                return Err(Error::Usage(String::from(
+                    "Feedback can only be recorded for a single part",
+                )))
+            }
+        };
+        let answer = Answer(solution.to_string());
+        match feedback {
+            Feedback::Accepted => guesses.accepted = Some(answer),
+            Feedback::Wrong => guesses.wrong.push(answer),
+            Feedback::TooHigh => guesses.too_high.push(answer),
+            Feedback::TooLow => guesses.too_low.push(answer),
+        }
+        Ok(())
+    }
+}
+
+impl Guesses {
+    pub fn check(&self, answer: &Answer) -> Verdict<'_> {
+        if let Some(accepted) = &self.accepted {
+            return match accepted == answer {
+                true => Verdict::Accepted,
+                false => Verdict::NotAccepted(accepted),
+            };
+        }
+        if self.wrong.contains(answer) {
+            return Verdict::KnownWrong;
+        }
+        let Some(value) = answer.as_number() else {
+            return Verdict::Unknown;
+        };
+        let lowest_too_high = self
+            .too_high
+            .iter()
+            .filter(|bound| bound.as_number().is_some_and(|bound| value >= bound))
+            .min_by_key(|bound| bound.as_number());
+        if let Some(bound) = lowest_too_high {
+            return Verdict::TooHigh(bound);
+        }
+        let highest_too_low = self
+            .too_low
+            .iter()
+            .filter(|bound| bound.as_number().is_some_and(|bound| value <= bound))
+            .max_by_key(|bound| bound.as_number());
+        match highest_too_low {
+            Some(bound) => Verdict::TooLow(bound),
+            None => Verdict::Unknown,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.accepted.is_none()
+            && self.wrong.is_empty()
+            && self.too_high.is_empty()
+            && self.too_low.is_empty()
+    }
+}
+
+impl Answer {
+    fn as_number(&self) -> Option<i128> {
+        self.0.parse().ok()
+    }
+}
+
+fn key(day: u8) -> String {
+    format!("day-{day:02}")
+}
+
+impl From<RawAnswer> for Answer {
+    fn from(raw: RawAnswer) -> Self {
+        match raw {
+            RawAnswer::Number(number) => Answer(number.to_string()),
+            RawAnswer::Text(text) => Answer(text),
+        }
+    }
+}
+
+impl From<Answer> for RawAnswer {
+    fn from(Answer(raw): Answer) -> Self {
+        match raw.parse() {
+            Ok(number) => RawAnswer::Number(number),
+            Err(_) => RawAnswer::Text(raw),
+        }
+    }
+}
+
+impl std::fmt::Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::str::FromStr for Feedback {
+    type Err = Error;
+
+    fn from_str(raw: &str) -> Result<Self> {
+        match raw {
+            "accepted" => Ok(Feedback::Accepted),
+            "wrong" => Ok(Feedback::Wrong),
+            "too-high" => Ok(Feedback::TooHigh),
+            "too-low" => Ok(Feedback::TooLow),
+            _ => Err(Error::Usage(format!("Unknown feedback {raw:?}"))),
+        }
+    }
+}
+
+impl std::fmt::Display for Verdict<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Accepted => write!(f, "matches the accepted answer"),
+            Verdict::NotAccepted(accepted) => write!(f, "differs from the accepted {accepted}"),
+            Verdict::KnownWrong => write!(f, "known to be wrong"),
+            Verdict::TooHigh(bound) => write!(f, "not below the known too high {bound}"),
+            Verdict::TooLow(bound) => write!(f, "not above the known too low {bound}"),
+            Verdict::Unknown => write!(f, "not yet checked"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEDGER: &str = r#"
+        [day-14.first]
+        accepted = 105784
+
+        [day-14.second]
+        wrong = [95001]
+        too-high = [100000, 99000]
+        too-low = ["90000"]
+    "#;
+
+    #[test]
+    fn verdicts() {
+        let ledger: Ledger = toml::from_str(LEDGER).unwrap();
+        let check = |variant, solution: u64| ledger.check(14, variant, &solution).to_string();
+        assert_eq!(check(Variant::First, 105784), "matches the accepted answer");
+        assert_eq!(
+            check(Variant::First, 105785),
+            "differs from the accepted 105784"
+        );
+        assert_eq!(check(Variant::Second, 95001), "known to be wrong");
+        assert_eq!(
+            check(Variant::Second, 99500),
+            "not below the known too high 99000"
+        );
+        assert_eq!(
+            check(Variant::Second, 90000),
+            "not above the known too low 90000"
+        );
+        assert_eq!(check(Variant::Second, 95000), "not yet checked");
+        assert_eq!(ledger.check(13, Variant::First, &0), Verdict::Unknown);
+    }
+
+    #[test]
+    fn recording_round_trips() -> Result {
+        let mut ledger = Ledger::default();
+        ledger.record(1, Variant::Second, Feedback::TooLow, &"12")?;
+        ledger.record(1, Variant::Second, Feedback::Accepted, &"abc")?;
+        assert!(ledger
+            .record(1, Variant::Both, Feedback::Wrong, &1)
+            .is_err());
+        let raw = toml::to_string(&ledger)?;
+        assert_eq!(raw, "[day-01.second]\naccepted = \"abc\"\ntoo-low = [12]\n");
+        let ledger: Ledger = toml::from_str(&raw)?;
+        assert_eq!(ledger.check(1, Variant::Second, &"abc"), Verdict::Accepted);
+        Ok(())
+    }
+}
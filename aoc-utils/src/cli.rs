@@ -0,0 +1,80 @@
+use clap::{ArgAction, Parser};
+
+use crate::{Error, Feedback, Result, Variant};
+
+/// Solve an Advent of Code puzzle
+#[derive(Debug, Parser)]
+pub struct Cli {
+    /// The puzzle to solve: 1, 2 or both
+    #[arg(short, long, value_name = "PART")]
+    pub part: Option<Variant>,
+    /// Read the puzzle input from this file
+    #[arg(short, long, value_name = "PATH", conflicts_with = "stdin")]
+    pub input: Option<String>,
+    /// Read the puzzle input from stdin
+    #[arg(long)]
+    pub stdin: bool,
+    /// Repeat the run and print how long parsing and solving took
+    #[arg(
+        long,
+        value_name = "RUNS",
+        num_args = 0..=1,
+        default_missing_value = "10",
+        requires = "input"
+    )]
+    pub time: Option<usize>,
+    /// Check solutions against the answers recorded in this file
+    #[arg(long, value_name = "PATH", default_value = "answers.toml")]
+    pub answers: String,
+    /// Record the feedback for the solution: accepted, wrong, too-high or too-low
+    #[arg(long, value_name = "FEEDBACK")]
+    pub record: Option<Feedback>,
+    /// Only print the solution
+    #[arg(short, long)]
+    pub quiet: bool,
+    /// Log diagnostics to stderr, repeat for more detail
+    #[arg(short, long, action = ArgAction::Count)]
+    pub verbose: u8,
+}
+
+impl Cli {
+    /// Parse the process arguments.
+    ///
+    /// Returns `None` if the help was requested and already printed.
+    pub fn from_env() -> Result<Option<Self>> {
+        match Cli::try_parse() {
+            Ok(cli) => Ok(Some(cli)),
+            // Help and version output
+            Err(why) if !why.use_stderr() => {
+                why.print()?;
+                Ok(None)
+            }
+            Err(why) => {
+                let why = why.render().to_string();
+                let why = why.trim_start_matches("error: ").trim_end();
+                Err(Error::Usage(why.to_owned()))
+            }
+        }
+    }
+
+    pub fn file(&self) -> String {
+        match &self.input {
+            Some(file) => file.clone(),
+            None => {
+                if !self.stdin && !self.quiet {
+                    eprintln!("> Reading from stdin..");
+                }
+                String::from("-")
+            }
+        }
+    }
+
+    pub fn variant(&self) -> Variant {
+        self.part.unwrap_or_else(|| {
+            if !self.quiet {
+                eprintln!("> No part given, solving first puzzle");
+            }
+            Variant::First
+        })
+    }
+}
@@ -0,0 +1,145 @@
+//! Find where an iterated simulation starts repeating itself.
+//!
+//! A simulation is given as its `start` state and a `step` closure computing
+//! the next state. The detectors return `(prefix_len, cycle_len)`: the state
+//! after `prefix_len` steps is the first one to come back, and it does so
+//! every `cycle_len` steps from then on.
+//!
+//! [`brent`] and [`floyd`] only keep a few states around but step more often,
+//! [`hashed`] steps least but remembers every state.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Brent's algorithm, usually needing fewer steps than [`floyd`].
+pub fn brent<S, F>(start: S, mut step: F) -> (usize, usize)
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    let (mut power, mut cycle_len) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == cycle_len {
+            tortoise = hare.clone();
+            power *= 2;
+            cycle_len = 0;
+        }
+        hare = step(&hare);
+        cycle_len += 1;
+    }
+    let mut hare = start.clone();
+    for _ in 0..cycle_len {
+        hare = step(&hare);
+    }
+    (prefix_len(start, hare, step), cycle_len)
+}
+
+/// Floyd's tortoise and hare.
+pub fn floyd<S, F>(start: S, mut step: F) -> (usize, usize)
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+    let prefix_len = prefix_len(start.clone(), hare, &mut step);
+    let mut tortoise = start;
+    for _ in 0..prefix_len {
+        tortoise = step(&tortoise);
+    }
+    let mut cycle_len = 1;
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        cycle_len += 1;
+    }
+    (prefix_len, cycle_len)
+}
+
+/// Remember every state until one comes back.
+pub fn hashed<S, F>(start: S, mut step: F) -> (usize, usize)
+where
+    S: Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut state = start;
+    for idx in 0.. {
+        if let Some(&first) = seen.get(&state) {
+            return (first, idx - first);
+        }
+        let next = step(&state);
+        seen.insert(state, idx);
+        state = next;
+    }
+    unreachable!("more states than fit in memory")
+}
+
+/// The state after `steps` steps, skipping ahead once a cycle is found.
+pub fn state_after<S, F>(start: S, steps: usize, mut step: F) -> S
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut history = vec![];
+    let mut state = start;
+    for idx in 0..steps {
+        if let Some(&first) = seen.get(&state) {
+            let cycle_len = idx - first;
+            return history.swap_remove(first + (steps - first) % cycle_len);
+        }
+        let next = step(&state);
+        seen.insert(state.clone(), idx);
+        history.push(state);
+        state = next;
+    }
+    state
+}
+
+/// The number of steps until a state and the state `cycle_len` steps ahead of it meet.
+fn prefix_len<S, F>(mut tortoise: S, mut hare: S, mut step: F) -> usize
+where
+    S: PartialEq,
+    F: FnMut(&S) -> S,
+{
+    let mut prefix_len = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix_len += 1;
+    }
+    prefix_len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts 0, 1, 2, 3, 4, 5 and then loops through 3, 4, 5 forever.
+    fn step(&state: &usize) -> usize {
+        match state {
+            5 => 3,
+            _ => state + 1,
+        }
+    }
+
+    #[test]
+    fn detectors_agree() {
+        assert_eq!(brent(0, step), (3, 3));
+        assert_eq!(floyd(0, step), (3, 3));
+        assert_eq!(hashed(0, step), (3, 3));
+        assert_eq!(brent(4, step), (0, 3));
+        assert_eq!(floyd(4, step), (0, 3));
+        assert_eq!(hashed(4, step), (0, 3));
+        assert_eq!(state_after(0, 2, step), 2);
+        assert_eq!(state_after(0, 6, step), 3);
+        assert_eq!(state_after(0, 1_000_000_000, step), 4);
+    }
+}
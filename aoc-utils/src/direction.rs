@@ -0,0 +1,174 @@
+use crate::Idx2D;
+
+/// One of the four directions on a [`Grid`](crate::Grid), with north facing up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// One of the eight directions on a [`Grid`](crate::Grid), including diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// All directions, clockwise starting at north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Direction> {
+        Self::ALL.into_iter()
+    }
+
+    pub fn invert(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::East | Direction::West)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+
+    /// A distinct bit per direction, to store a set of directions in a `u8`.
+    pub fn bit(self) -> u8 {
+        1 << self as u8
+    }
+
+    /// The directions whose [`bit`](Direction::bit) is set in `bits`.
+    pub fn from_bits(bits: u8) -> impl Iterator<Item = Direction> {
+        Self::iter().filter(move |dir| bits & dir.bit() != 0)
+    }
+
+    /// The arrow pointing this way, as used in puzzle inputs.
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        }
+    }
+}
+
+impl Direction8 {
+    /// All directions, clockwise starting at north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Direction8> {
+        Self::ALL.into_iter()
+    }
+
+    pub fn invert(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Turn by 45 degrees counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Turn by 45 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as u8 % 2 == 1
+    }
+
+    pub fn bit(self) -> u8 {
+        1 << self as u8
+    }
+
+    pub fn from_bits(bits: u8) -> impl Iterator<Item = Direction8> {
+        Self::iter().filter(move |dir| bits & dir.bit() != 0)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        Direction8::ALL[dir as usize * 2]
+    }
+}
+
+impl From<Direction> for Idx2D<isize> {
+    fn from(dir: Direction) -> Self {
+        Direction8::from(dir).into()
+    }
+}
+
+impl From<Direction8> for Idx2D<isize> {
+    fn from(dir: Direction8) -> Self {
+        match dir {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning_and_inverting() {
+        for dir in Direction::iter() {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.invert());
+            let (x, y): Idx2D<isize> = dir.into();
+            assert_eq!(Idx2D::from(dir.invert()), (-x, -y));
+        }
+        for dir in Direction8::iter() {
+            let (x, y): Idx2D<isize> = dir.into();
+            assert_eq!(Idx2D::from(dir.invert()), (-x, -y));
+        }
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction8::North.turn_left(), Direction8::NorthWest);
+        let bits = Direction::North.bit() | Direction::West.bit();
+        assert_eq!(
+            Direction::from_bits(bits).collect::<Vec<_>>(),
+            [Direction::North, Direction::West]
+        );
+    }
+}
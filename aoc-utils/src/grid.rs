@@ -1,24 +1,52 @@
-use std::{fs::File, io::BufReader};
+use std::{fmt::Write as _, io::BufRead};
 
-use crate::{Args, Error, Input};
+use crate::{Args, Direction, Direction8, Error, Input, ParseError};
 
 pub type Idx2D<T = usize> = (T, T);
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
-    inner: Vec<T>,
-    width: usize,
+    pub(crate) inner: Vec<T>,
+    pub(crate) width: usize,
 }
 
 impl<T> Grid<T> {
     pub fn height(&self) -> usize {
-        self.inner.len() / self.width
+        self.inner.len().checked_div(self.width).unwrap_or(0)
     }
 
     pub fn width(&self) -> usize {
         self.width
     }
 
+    /// Build a grid from rows which all have to be equally long.
+    pub fn from_rows<I, R>(rows: I) -> crate::Result<Self>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+    {
+        let mut width = None;
+        let mut inner = vec![];
+        for (y, row) in rows.into_iter().enumerate() {
+            let before = inner.len();
+            inner.extend(row);
+            let len = inner.len() - before;
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    return Err(Error::input(format!(
+                        "Row {y} has {len} cells instead of {width}"
+                    )))
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(Grid {
+            width: width.unwrap_or_default(),
+            inner,
+        })
+    }
+
     pub fn find_idx<F>(&self, f: F) -> Option<Idx2D>
     where
         F: Fn(&T) -> bool,
@@ -36,18 +64,30 @@ impl<T> Grid<T> {
         Grid { width, inner }
     }
 
-    pub fn debug_render<F, S>(&self, mut f: F)
+    pub fn debug_render<F, S>(&self, f: F)
+    where
+        F: FnMut(Idx2D, &T) -> S,
+        S: std::fmt::Display,
+    {
+        eprint!("\n{}", self.render(f));
+    }
+
+    /// Render every cell with `f`, ending each row with a newline.
+    ///
+    /// Meant for logging, like `debug!("\n{}", grid.render(..))`.
+    pub fn render<F, S>(&self, mut f: F) -> String
     where
         F: FnMut(Idx2D, &T) -> S,
         S: std::fmt::Display,
     {
+        let mut rendered = String::new();
         for (idx, elem) in self.inner.iter().enumerate() {
-            if idx % self.width == 0 {
-                eprintln!();
+            write!(rendered, "{}", f(self.idx_to_pos(idx), elem)).unwrap();
+            if idx % self.width == self.width - 1 {
+                rendered.push('\n');
             }
-            eprint!("{}", f(self.idx_to_pos(idx), elem));
         }
-        eprintln!();
+        rendered
     }
 
     pub fn iter_pos(&self) -> impl Iterator<Item = (Idx2D, &T)> {
@@ -95,6 +135,75 @@ impl<T> Grid<T> {
         }
     }
 
+    /// The orthogonal neighbors of `pos` inside the grid.
+    pub fn neighbors4(&self, pos: Idx2D) -> impl Iterator<Item = (Idx2D, &T)> + '_ {
+        self.neighbors_with(pos, Direction::ALL)
+            .map(|(_, pos, val)| (pos, val))
+    }
+
+    /// The orthogonal and diagonal neighbors of `pos` inside the grid.
+    pub fn neighbors8(&self, pos: Idx2D) -> impl Iterator<Item = (Idx2D, &T)> + '_ {
+        self.neighbors_with(pos, Direction8::ALL)
+            .map(|(_, pos, val)| (pos, val))
+    }
+
+    /// The neighbors of `pos` in the given directions, with the direction taken.
+    pub fn neighbors_with<D, I>(&self, pos: Idx2D, dirs: I) -> impl Iterator<Item = (D, Idx2D, &T)>
+    where
+        D: Into<Idx2D<isize>> + Copy,
+        I: IntoIterator<Item = D>,
+    {
+        dirs.into_iter().filter_map(move |dir| {
+            let next = self.walk(pos, dir)?;
+            Some((dir, next, &self[next]))
+        })
+    }
+
+    pub fn neighbors4_mut(&mut self, pos: Idx2D) -> impl Iterator<Item = (Idx2D, &mut T)> {
+        self.neighbors_with_mut(pos, Direction::ALL)
+            .map(|(_, pos, val)| (pos, val))
+    }
+
+    pub fn neighbors8_mut(&mut self, pos: Idx2D) -> impl Iterator<Item = (Idx2D, &mut T)> {
+        self.neighbors_with_mut(pos, Direction8::ALL)
+            .map(|(_, pos, val)| (pos, val))
+    }
+
+    /// Like [`Grid::neighbors_with`], but mutable.
+    ///
+    /// Directions leading to the same cell yield it only once.
+    pub fn neighbors_with_mut<D, I>(
+        &mut self,
+        pos: Idx2D,
+        dirs: I,
+    ) -> impl Iterator<Item = (D, Idx2D, &mut T)>
+    where
+        D: Into<Idx2D<isize>> + Copy,
+        I: IntoIterator<Item = D>,
+    {
+        let mut targets: Vec<_> = dirs
+            .into_iter()
+            .filter_map(|dir| Some((self.pos_to_idx(self.walk(pos, dir)?), dir)))
+            .enumerate()
+            .collect();
+        // Hand out the cells in memory order, then restore the order of `dirs`
+        targets.sort_by_key(|(_, (idx, _))| *idx);
+        targets.dedup_by_key(|(_, (idx, _))| *idx);
+        let width = self.width;
+        // Split off every cell in turn, the sorted indices keep the borrows disjoint
+        let (mut rest, mut offset) = (&mut self.inner[..], 0);
+        let mut found = Vec::with_capacity(targets.len());
+        for (order, (idx, dir)) in targets {
+            let (val, tail) = std::mem::take(&mut rest)[idx - offset..]
+                .split_first_mut()
+                .expect("neighbors are inside the grid");
+            (rest, offset) = (tail, idx + 1);
+            found.push((order, (dir, (idx % width, idx / width), val)));
+        }
+        found.sort_by_key(|(order, _)| *order);
+        found.into_iter().map(|(_, neighbor)| neighbor)
+    }
+
     pub fn col(&self, x: usize) -> impl Iterator<Item = (Idx2D, &T)> + '_ {
         (0..self.height()).map(move |y| ((x, y), &self[(x, y)]))
     }
@@ -118,25 +227,79 @@ where
     Error: From<E>,
 {
     fn from_args(args: Args) -> crate::Result<Self> {
-        let file = File::open(args.file)?;
-        let reader = BufReader::new(file);
+        let origin = args.source.name();
+        Self::from_reader(args.source.open()?, &origin)
+    }
+
+    fn from_args_twice(args: Args) -> crate::Result<(Self, Self)> {
+        let origin = args.source.name();
+        let raw = args.source.read_all()?;
+        Ok((
+            Self::from_reader(&raw[..], &origin)?,
+            Self::from_reader(&raw[..], &origin)?,
+        ))
+    }
+}
+
+impl<T, E> Grid<T>
+where
+    T: TryFrom<u8, Error = E>,
+    Error: From<E>,
+{
+    /// Parse one cell per byte, rejecting rows of different lengths.
+    ///
+    /// Lines may end in `\r\n`, and blank lines at the end are ignored.
+    fn from_reader<R: BufRead>(reader: R, origin: &str) -> crate::Result<Self> {
         let mut width = None;
         let mut inner = vec![];
-        let mut byte_count = 0;
-        for byte in std::io::Read::bytes(reader) {
-            let byte = byte?;
-            match byte {
-                b'\n' if width.is_none() => width = Some(byte_count),
-                b'\n' => {}
-                byte => {
-                    let val = T::try_from(byte)?;
-                    inner.push(val)
-                }
+        let mut blank_since = None;
+        for (y, row) in reader.split(b'\n').enumerate() {
+            let mut row = row?;
+            if row.last() == Some(&b'\r') {
+                row.pop();
+            }
+            let error = |column: usize, len: usize, message: String| {
+                Error::Parse(Box::new(ParseError {
+                    origin: origin.to_owned(),
+                    line: y + 1,
+                    column: column + 1,
+                    len,
+                    text: String::from_utf8_lossy(&row).into_owned(),
+                    message,
+                }))
+            };
+            if row.is_empty() {
+                blank_since.get_or_insert(y);
+                continue;
+            }
+            if let Some(blank) = blank_since {
+                return Err(Error::Parse(Box::new(ParseError {
+                    origin: origin.to_owned(),
+                    line: blank + 1,
+                    column: 1,
+                    len: 0,
+                    text: String::new(),
+                    message: String::from("Blank line inside the grid"),
+                })));
+            }
+            let width = *width.get_or_insert(row.len());
+            if row.len() != width {
+                let message = format!("Row has {} cells instead of {width}", row.len());
+                return Err(match row.len() > width {
+                    true => error(width, row.len() - width, message),
+                    false => error(row.len(), 1, message),
+                });
+            }
+            for (x, byte) in row.iter().enumerate() {
+                let cell = T::try_from(*byte).map_err(|why| match Error::from(why) {
+                    Error::Input(why) => error(x, 1, why),
+                    why => error(x, 1, why.to_string()),
+                })?;
+                inner.push(cell);
             }
-            byte_count += 1
         }
         Ok(Grid {
-            width: width.unwrap_or(byte_count),
+            width: width.unwrap_or_default(),
             inner,
         })
     }
@@ -180,3 +343,76 @@ impl<T> std::ops::IndexMut<Idx2D> for Grid<T> {
         &mut self.inner[idx]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{Args, Direction, Grid, Input, Variant};
+
+    fn grid(raw: &str) -> Grid<u8> {
+        Grid::from_args(Args::from_memory(Variant::First, raw)).unwrap()
+    }
+
+    #[test]
+    fn loading_is_strict() {
+        let load = |raw: &str| Grid::<u8>::from_args(Args::from_memory(Variant::First, raw));
+        for raw in ["ab\ncd", "ab\ncd\n", "ab\r\ncd\r\n", "ab\ncd\n\n\n"] {
+            assert_eq!(load(raw).unwrap(), grid("ab\ncd\n"), "{raw:?}");
+        }
+        let why = load("ab\nabc\nab\n").unwrap_err().to_string();
+        assert_eq!(
+            why,
+            "<memory>:2:3: Row has 3 cells instead of 2\n  |\n2 | abc\n  |   ^"
+        );
+        let why = load("ab\n\nab\n").unwrap_err().to_string();
+        assert!(why.starts_with("<memory>:2:1: Blank line inside the grid"));
+        let why = Grid::<Digit>::from_args(Args::from_memory(Variant::First, "12\n3x\n"));
+        assert!(why
+            .unwrap_err()
+            .to_string()
+            .starts_with("<memory>:2:2: Not a digit"));
+    }
+
+    #[derive(Debug)]
+    struct Digit;
+
+    impl TryFrom<u8> for Digit {
+        type Error = crate::Error;
+
+        fn try_from(raw: u8) -> crate::Result<Self> {
+            match raw.is_ascii_digit() {
+                true => Ok(Digit),
+                false => Err(crate::Error::input("Not a digit")),
+            }
+        }
+    }
+
+    #[test]
+    fn neighbors_stay_inside() {
+        let grid = grid("abc\ndef\nghi\n");
+        let values = |iter: &mut dyn Iterator<Item = (_, &u8)>| -> String {
+            iter.map(|(_, val)| *val as char).collect()
+        };
+        assert_eq!(values(&mut grid.neighbors4((1, 1))), "bfhd");
+        assert_eq!(values(&mut grid.neighbors4((0, 0))), "bd");
+        assert_eq!(values(&mut grid.neighbors8((1, 1))), "bcfihgda");
+        assert_eq!(values(&mut grid.neighbors8((2, 2))), "fhe");
+        let with_dirs: Vec<_> = grid
+            .neighbors_with((0, 2), [Direction::North, Direction::West])
+            .collect();
+        assert_eq!(with_dirs, [(Direction::North, (0, 1), &b'd')]);
+    }
+
+    #[test]
+    fn neighbors_can_be_changed() {
+        let mut grid = grid("abc\ndef\nghi\n");
+        grid.neighbors8_mut((0, 1))
+            .for_each(|(_, val)| *val = val.to_ascii_uppercase());
+        let mut order = vec![];
+        for (dir, _, val) in grid.neighbors_with_mut((1, 1), [Direction::West, Direction::North]) {
+            order.push(dir);
+            *val = b'*';
+        }
+        assert_eq!(order, [Direction::West, Direction::North]);
+        assert_eq!(grid.map(char::from).to_string(), "\nA*c\n*Ef\nGHi\n");
+    }
+}
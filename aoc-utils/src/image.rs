@@ -0,0 +1,217 @@
+//! Grid snapshots as image files, only built with the `viz` feature.
+//!
+//! [`Grid::to_image`] draws every cell as a square in the colour picked by a
+//! closure, and [`Frames`] saves one numbered image per simulation step to
+//! turn into a video later.
+
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{Error, Grid, Idx2D, Result};
+
+/// The colour of a pixel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+}
+
+impl From<colorous::Color> for Rgb {
+    fn from(color: colorous::Color) -> Self {
+        Rgb(color.r, color.g, color.b)
+    }
+}
+
+/// A picture of a grid, every cell drawn as a square of pixels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl<T> Grid<T> {
+    /// Draw every cell in the colour given by `color`, as a square of
+    /// `scale` by `scale` pixels.
+    pub fn to_image<F, C>(&self, scale: usize, mut color: F) -> Image
+    where
+        F: FnMut(Idx2D, &T) -> C,
+        C: Into<Rgb>,
+    {
+        assert!(scale > 0, "the scale has to be positive");
+        let width = self.width() * scale;
+        let colors: Vec<Rgb> = self
+            .iter_pos()
+            .map(|(pos, cell)| color(pos, cell).into())
+            .collect();
+        let pixels = (0..self.height() * scale)
+            .flat_map(|y| (0..width).map(move |x| (x / scale, y / scale)))
+            .map(|(x, y)| colors[y * self.width() + x])
+            .collect();
+        Image {
+            width,
+            height: self.height() * scale,
+            pixels,
+        }
+    }
+}
+
+impl Image {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixel(&self, (x, y): Idx2D) -> Option<Rgb> {
+        match x < self.width {
+            true => self.pixels.get(y * self.width + x).copied(),
+            false => None,
+        }
+    }
+
+    /// Write as a binary PPM, which most tools including `ffmpeg` can read.
+    pub fn write_ppm<W: Write>(&self, mut out: W) -> Result {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.bytes())?;
+        Ok(out.flush()?)
+    }
+
+    pub fn write_png<W: Write>(&self, out: W) -> Result {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.bytes())?;
+        Ok(writer.finish()?)
+    }
+
+    /// Save as PPM or PNG, depending on the extension of `path`.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result {
+        let path = path.as_ref();
+        let extension = path.extension().and_then(|ext| ext.to_str());
+        if !matches!(extension, Some("ppm" | "png")) {
+            return Err(Error::Other(format!(
+                "Images can only be saved as .ppm or .png, not {}",
+                path.display()
+            )));
+        }
+        let out = BufWriter::new(File::create(path)?);
+        match extension {
+            Some("ppm") => self.write_ppm(out),
+            _ => self.write_png(out),
+        }
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|&Rgb(r, g, b)| [r, g, b])
+            .collect()
+    }
+}
+
+/// Numbered images of a simulation as it goes, like `frames/00042.png`.
+///
+/// They can be turned into a video with
+/// `ffmpeg -framerate 30 -i frames/%05d.png beams.mp4`.
+#[derive(Debug, Clone)]
+pub struct Frames {
+    dir: PathBuf,
+    scale: usize,
+    count: usize,
+}
+
+impl Frames {
+    /// Save frames into `dir`, creating it if necessary, with every cell
+    /// `scale` pixels wide.
+    pub fn new<P: Into<PathBuf>>(dir: P, scale: usize) -> Result<Self> {
+        if scale == 0 {
+            return Err(Error::Other(String::from(
+                "Frames need a scale of at least one pixel per cell",
+            )));
+        }
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Frames {
+            dir,
+            scale,
+            count: 0,
+        })
+    }
+
+    /// Save frames into the directory in `AOC_FRAMES`, if it is set.
+    pub fn from_env(scale: usize) -> Result<Option<Self>> {
+        match std::env::var_os("AOC_FRAMES") {
+            Some(dir) => Frames::new(dir, scale).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Save the next frame, giving back its path.
+    pub fn push<T, F, C>(&mut self, grid: &Grid<T>, color: F) -> Result<PathBuf>
+    where
+        F: FnMut(Idx2D, &T) -> C,
+        C: Into<Rgb>,
+    {
+        let path = self.dir.join(format!("{:05}.png", self.count));
+        grid.to_image(self.scale, color).save(&path)?;
+        self.count += 1;
+        Ok(path)
+    }
+
+    /// The number of frames saved so far.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Args, Input, Variant};
+
+    #[test]
+    fn scaled_images_and_frames() -> crate::Result {
+        let grid = Grid::<u8>::from_args(Args::from_memory(Variant::First, "#.\n.#\n"))?;
+        let color = |_, &cell: &u8| match cell {
+            b'#' => Rgb::WHITE,
+            _ => Rgb::BLACK,
+        };
+        let image = grid.to_image(2, color);
+        assert_eq!((image.width(), image.height()), (4, 4));
+        assert_eq!(image.pixel((1, 1)), Some(Rgb::WHITE));
+        assert_eq!(image.pixel((2, 1)), Some(Rgb::BLACK));
+        assert_eq!(image.pixel((3, 3)), Some(Rgb::WHITE));
+        assert_eq!(image.pixel((4, 0)), None);
+
+        let mut ppm = vec![];
+        grid.to_image(1, color).write_ppm(&mut ppm)?;
+        assert_eq!(ppm[..11], *b"P6\n2 2\n255\n");
+        assert_eq!(ppm[11..], [255, 255, 255, 0, 0, 0, 0, 0, 0, 255, 255, 255]);
+        let mut png = vec![];
+        image.write_png(&mut png)?;
+        assert_eq!(png[..8], *b"\x89PNG\r\n\x1a\n");
+
+        let dir = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        assert!(Frames::new(&dir, 0).is_err());
+        let mut frames = Frames::new(&dir, 3)?;
+        frames.push(&grid, color)?;
+        let path = frames.push(&grid, |_, _| Rgb(255, 0, 0))?;
+        assert_eq!(path, dir.join("00001.png"));
+        assert_eq!(frames.len(), 2);
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
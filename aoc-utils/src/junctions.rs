@@ -0,0 +1,126 @@
+use std::{collections::HashMap, fmt::Write as _};
+
+use crate::{Direction, Grid, Idx2D};
+
+/// A maze compressed to its junctions and the corridors between them, see
+/// [`Grid::junction_graph`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JunctionGraph {
+    /// The grid position of every node, starting with the explicitly requested points.
+    pub nodes: Vec<Idx2D>,
+    /// For every node, the nodes at the other end of its corridors with their length.
+    pub edges: Vec<Vec<(usize, usize)>>,
+    index: HashMap<Idx2D, usize>,
+}
+
+impl JunctionGraph {
+    /// The index of the node at `pos`.
+    pub fn node(&self, pos: Idx2D) -> Option<usize> {
+        self.index.get(&pos).copied()
+    }
+
+    /// Render in the DOT language of Graphviz, labelling nodes with their position.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        for (idx, (x, y)) in self.nodes.iter().enumerate() {
+            writeln!(dot, "    {idx} [label=\"{x},{y}\"];").unwrap();
+        }
+        for (from, edges) in self.edges.iter().enumerate() {
+            for (to, len) in edges {
+                writeln!(dot, "    {from} -> {to} [label={len}];").unwrap();
+            }
+        }
+        dot.push('}');
+        dot
+    }
+}
+
+impl<T> Grid<T> {
+    /// Compress the `passable` cells into a graph of junctions.
+    ///
+    /// Junctions are cells with more than two passable neighbors, and every
+    /// position in `points` becomes a node too, in the given order. A cell for
+    /// which `one_way` gives a direction can only be left in that direction.
+    pub fn junction_graph<P, O>(&self, points: &[Idx2D], passable: P, one_way: O) -> JunctionGraph
+    where
+        P: Fn(Idx2D, &T) -> bool,
+        O: Fn(Idx2D, &T) -> Option<Direction>,
+    {
+        let passable = &passable;
+        let open_neighbors = |pos| {
+            self.neighbors4(pos)
+                .filter(|&(next, val)| passable(next, val))
+                .count()
+        };
+        let junctions = self
+            .iter_pos()
+            .filter(|&(pos, val)| passable(pos, val) && open_neighbors(pos) > 2)
+            .map(|(pos, _)| pos);
+        let mut nodes: Vec<Idx2D> = points.to_vec();
+        nodes.extend(junctions.filter(|pos| !points.contains(pos)));
+        let index: HashMap<_, _> = nodes
+            .iter()
+            .enumerate()
+            .map(|(idx, &pos)| (pos, idx))
+            .collect();
+
+        let exits = |pos: Idx2D| {
+            let forced = one_way(pos, &self[pos]);
+            self.neighbors_with(pos, Direction::ALL)
+                .filter(move |&(dir, next, val)| {
+                    forced.is_none_or(|forced| forced == dir) && passable(next, val)
+                })
+                .map(|(_, next, _)| next)
+        };
+        let edges = nodes
+            .iter()
+            .enumerate()
+            .map(|(from, &start)| {
+                exits(start)
+                    .filter_map(|first| {
+                        // Follow the corridor until it reaches a node or ends
+                        let (mut prev, mut curr, mut len) = (start, first, 1);
+                        while !index.contains_key(&curr) {
+                            let next = exits(curr).find(|&next| next != prev)?;
+                            (prev, curr, len) = (curr, next, len + 1);
+                        }
+                        Some((index[&curr], len))
+                    })
+                    .filter(|&(to, _)| to != from)
+                    .collect()
+            })
+            .collect();
+        JunctionGraph {
+            nodes,
+            edges,
+            index,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Args, Direction, Grid, Input, Variant};
+
+    #[test]
+    fn corridors_become_edges() -> crate::Result {
+        let raw = "#.###\n#.>.#\n#.#.#\n#...#\n###.#\n";
+        let grid = Grid::<u8>::from_args(Args::from_memory(Variant::First, raw))?;
+        let points = [(1, 0), (3, 4)];
+        let open = |_, &cell: &u8| cell != b'#';
+        let graph = grid.junction_graph(&points, open, |_, _| None);
+        assert_eq!(graph.nodes, [(1, 0), (3, 4), (1, 1), (3, 3)]);
+        assert_eq!(graph.node((1, 1)), Some(2));
+        assert_eq!(graph.edges[0], [(2, 1)]);
+        assert_eq!(graph.edges[2], [(0, 1), (3, 4), (3, 4)]);
+        assert_eq!(graph.edges[3], [(2, 4), (1, 1), (2, 4)]);
+        assert!(graph.to_dot().contains("    2 [label=\"1,1\"];\n"));
+        assert!(graph.to_dot().contains("    0 -> 2 [label=1];\n"));
+
+        let slope = |_, &cell: &u8| (cell == b'>').then_some(Direction::East);
+        let graph = grid.junction_graph(&points, open, slope);
+        assert_eq!(graph.edges[2], [(0, 1), (3, 4), (3, 4)]);
+        assert_eq!(graph.edges[3], [(1, 1), (2, 4)]);
+        Ok(())
+    }
+}
@@ -1,53 +1,156 @@
-use std::{fs::File, io::BufReader, num::ParseIntError};
+use std::num::ParseIntError;
 
 use thiserror::Error;
 
 #[macro_export]
 macro_rules! main {
-    ($problem:ident, $first_test_file:literal => $first_test_result:expr, $second_test_file:literal => $second_test_result:expr) => {
+    ($problem:ty) => {
         fn main() -> aoc_utils::Result {
             use aoc_utils::Problem;
-            $problem::run_with_input_output()
+            let day = env!("CARGO_PKG_NAME")
+                .strip_prefix("day-")
+                .and_then(|day| day.parse().ok());
+            <$problem>::run_with_input_output(day)
         }
+    };
+}
 
+/// Generate tests running a problem on example inputs from `inputs/`.
+///
+/// Every case becomes its own test named `first::<case>` or
+/// `second::<case>`. A part that is not solved yet can be marked `pending`,
+/// and attributes like `#[ignore]` apply to all cases of a part.
+///
+/// ```ignore
+/// aoc_utils::tests! {
+///     Day10,
+///     first {
+///         square_loop: "inputs-10-test-first-1" => 4,
+///         complex_loop: "inputs-10-test-first-2" => 8,
+///     },
+///     #[ignore = "too slow"]
+///     second { enclosed: "inputs-10-test-second" => 10 },
+/// }
+/// ```
+///
+/// The short form `tests!(Day01, "first-file" => 1, "second-file" => 2)`
+/// generates a single `example` case per part.
+#[macro_export]
+macro_rules! tests {
+    ($problem:ident, $first_test_file:literal => $first_test_result:expr, $second_test_file:literal => $second_test_result:expr) => {
+        $crate::tests! {
+            $problem,
+            first { example: $first_test_file => $first_test_result },
+            second { example: $second_test_file => $second_test_result },
+        }
+    };
+    ($problem:ident, $($(#[$attr:meta])* $part:ident $cases:tt),+ $(,)?) => {
+        $($crate::tests!(@part $problem, [$(#[$attr])*] $part $cases);)+
+    };
+    (@part $problem:ident, [$($attr:tt)*] $part:ident pending) => {
+        #[cfg(test)]
+        mod $part {
+            #[test]
+            #[ignore = "pending"]
+            fn pending() {}
+        }
+    };
+    (@part $problem:ident, $attrs:tt $part:ident { $($case:ident: $file:literal => $expected:expr),* $(,)? }) => {
+        #[cfg(test)]
+        mod $part {
+            use super::$problem;
+            $($crate::tests!(@case $problem, $attrs $part $case: $file => $expected);)*
+        }
+    };
+    (@case $problem:ident, [$($attr:tt)*] $part:ident $case:ident: $file:literal => $expected:expr) => {
         #[test]
-        fn test_input_works() -> aoc_utils::Result {
-            use aoc_utils::Problem;
-            // First
-            let file: &str = $first_test_file;
+        $($attr)*
+        fn $case() -> $crate::Result {
+            use $crate::Problem;
+            let file: &str = $file;
             let path = String::from("../inputs/") + file;
-            let args = aoc_utils::Args::from_raw(aoc_utils::Variant::First, path);
-            let exp = $first_test_result;
+            let args = $crate::Args::from_raw($crate::tests!(@variant $part), path);
             let solution = $problem::solve(args);
-            assert_eq!(exp, solution?);
-            // Second
-            let file: &str = $second_test_file;
-            let path = String::from("../inputs/") + file;
-            let args = aoc_utils::Args::from_raw(aoc_utils::Variant::Second, path);
-            let exp = $second_test_result;
-            let solution = $problem::solve(args);
-            assert_eq!(exp, solution?);
+            assert_eq!($crate::tests!(@solution $part, $expected), solution?);
             Ok(())
         }
     };
+    (@variant first) => { $crate::Variant::First };
+    (@variant second) => { $crate::Variant::Second };
+    (@solution first, $expected:expr) => { $crate::Solutions::First($expected) };
+    (@solution second, $expected:expr) => { $crate::Solutions::Second($expected) };
 }
 
+mod answers;
+mod cli;
+pub mod cycle;
+mod direction;
 mod grid;
+#[cfg(feature = "viz")]
+mod image;
+mod junctions;
 mod lines;
+pub mod logging;
+pub mod math;
+pub mod memo;
+pub mod parse;
+mod parse_error;
+pub mod ranges;
+mod region;
+pub mod search;
+mod source;
+mod sparse;
+mod tiled;
+mod timing;
+mod transform;
+#[cfg(feature = "viz")]
+pub mod viz;
 
+pub use answers::{Answer, Feedback, Guesses, Ledger, Verdict};
+pub use cli::Cli;
+pub use direction::{Direction, Direction8};
 pub use grid::{Grid, Idx2D};
-pub use lines::{InputLine, Lines, RawLine};
+#[cfg(feature = "viz")]
+pub use image::{Frames, Image, Rgb};
+pub use junctions::JunctionGraph;
+pub use lines::{InputLine, Lines, NumberedLine, RawLine};
+pub use parse_error::ParseError;
+pub use region::{Components, Region};
+pub use source::{Reader, Source};
+pub use sparse::SparseGrid;
+pub use tiled::TiledView;
+pub use timing::{Samples, Timings};
+pub use tracing;
+pub use transform::{GridView, GridViewMut};
 
-#[derive(Debug, Error)]
+#[derive(Error)]
 pub enum Error {
     #[error("IO: {_0}")]
     Io(#[from] std::io::Error),
-    #[error("Parsing a number")]
+    #[error("Parsing a number: {_0}")]
     ParseInt(#[from] ParseIntError),
-    #[error("Invalid input: {_0:?}")]
+    #[error("Invalid input: {_0}")]
     Input(String),
-    #[error("Error: {_0}")]
+    #[error("{_0}")]
+    Parse(Box<ParseError>),
+    #[error("{_0}")]
     Other(String),
+    #[error("{_0}")]
+    Usage(String),
+    #[error("Reading answers: {_0}")]
+    AnswersRead(#[from] toml::de::Error),
+    #[error("Writing answers: {_0}")]
+    AnswersWrite(#[from] toml::ser::Error),
+    #[cfg(feature = "viz")]
+    #[error("Writing PNG: {_0}")]
+    Png(#[from] png::EncodingError),
+}
+
+// `main` prints returned errors with `Debug`, which should be readable
+impl std::fmt::Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
 }
 
 impl Error {
@@ -63,31 +166,93 @@ pub trait Problem<I: Input> {
     /// Run the solver with the given arguments.
     fn solve_first(input: I) -> Result<Self::Solution>;
     fn solve_second(input: I) -> Result<Self::Solution>;
-    fn solve(args: Args) -> Result<Self::Solution> {
+    fn solve(args: Args) -> Result<Solutions<Self::Solution>> {
         match args.variant {
-            Variant::First => Self::solve_first(I::from_args(args)?),
-            Variant::Second => Self::solve_second(I::from_args(args)?),
+            Variant::First => Ok(Solutions::First(Self::solve_first(I::from_args(args)?)?)),
+            Variant::Second => Ok(Solutions::Second(Self::solve_second(I::from_args(args)?)?)),
+            Variant::Both => {
+                let (first, second) = I::from_args_twice(args)?;
+                Ok(Solutions::Both(
+                    Self::solve_first(first)?,
+                    Self::solve_second(second)?,
+                ))
+            }
         }
     }
-    fn run_with_input_output() -> Result {
-        let solution = Self::solve(Args {
-            file: std::env::args().nth(1).unwrap_or_else(|| {
-                eprintln!("> Reading from stdin..");
-                String::from("-")
-            }),
-            variant: {
-                let raw = std::env::args().nth(2).unwrap_or_else(|| {
-                    eprintln!("> No variant given, solving first puzzle");
-                    String::from("first")
-                });
-                match raw.as_str() {
-                    "first" => Variant::First,
-                    "second" => Variant::Second,
-                    _ => panic!("Unknown variant {raw:?}"),
+    /// Solve `runs` times, measuring parsing and solving separately.
+    fn time(args: Args, runs: usize) -> Result<(Solutions<Self::Solution>, Timings)> {
+        let mut timings = Timings::default();
+        let mut solutions = None;
+        for _ in 0..runs.max(1) {
+            let args = args.clone();
+            solutions = Some(match args.variant {
+                Variant::First => {
+                    let input = timings.parse.measure(|| I::from_args(args))?;
+                    Solutions::First(timings.first.measure(|| Self::solve_first(input))?)
+                }
+                Variant::Second => {
+                    let input = timings.parse.measure(|| I::from_args(args))?;
+                    Solutions::Second(timings.second.measure(|| Self::solve_second(input))?)
                 }
-            },
-        })?;
-        println!("{solution}");
+                Variant::Both => {
+                    // Time reading and one parse like for a single part, the
+                    // second copy is parsed from memory on the side
+                    let (raw, first) = timings.parse.measure(|| -> Result<_> {
+                        let raw = Args::from_memory(args.variant, args.source.read_all()?);
+                        let first = I::from_args(raw.clone())?;
+                        Ok((raw, first))
+                    })?;
+                    let second = I::from_args(raw)?;
+                    Solutions::Both(
+                        timings.first.measure(|| Self::solve_first(first))?,
+                        timings.second.measure(|| Self::solve_second(second))?,
+                    )
+                }
+            });
+        }
+        Ok((solutions.expect("at least one run"), timings))
+    }
+    /// Solve as requested on the command line.
+    ///
+    /// Solutions of a known `day` are checked against the answer [`Ledger`].
+    fn run_with_input_output(day: Option<u8>) -> Result {
+        let Some(cli) = Cli::from_env()? else {
+            return Ok(());
+        };
+        logging::init(cli.verbose);
+        let args = Args::from_raw(cli.variant(), cli.file());
+        if cli.record.is_some() && args.variant == Variant::Both {
+            return Err(Error::Usage(String::from(
+                "Feedback can only be recorded for a single part",
+            )));
+        }
+        let solutions = match cli.time {
+            Some(runs) => {
+                let (solutions, timings) = Self::time(args, runs)?;
+                println!("{solutions}");
+                println!("{timings}");
+                solutions
+            }
+            None => {
+                let solutions = Self::solve(args)?;
+                println!("{solutions}");
+                solutions
+            }
+        };
+        let Some(day) = day else {
+            return Ok(());
+        };
+        let mut ledger = Ledger::load(&cli.answers)?;
+        if let Some(feedback) = cli.record {
+            for (variant, solution) in solutions.iter() {
+                ledger.record(day, variant, feedback, solution)?;
+            }
+            ledger.save(&cli.answers)?;
+        } else if !cli.quiet {
+            for (variant, solution) in solutions.iter() {
+                eprintln!("> {variant}: {}", ledger.check(day, variant, solution));
+            }
+        }
         Ok(())
     }
 }
@@ -97,22 +262,109 @@ where
     Self: Sized,
 {
     fn from_args(args: Args) -> Result<Self>;
+    /// Create two copies of the input to solve both parts with.
+    ///
+    /// The default reads the whole input into memory once and parses it twice.
+    fn from_args_twice(args: Args) -> Result<(Self, Self)> {
+        let args = Args::from_memory(args.variant, args.source.read_all()?);
+        Ok((Self::from_args(args.clone())?, Self::from_args(args)?))
+    }
 }
 
+#[derive(Debug, Clone)]
 pub struct Args {
     pub variant: Variant,
-    pub file: String,
+    pub source: Source,
 }
 
 impl Args {
+    /// Read the input from the file at `file`, or from stdin if it is `-`.
     pub fn from_raw(variant: Variant, file: String) -> Self {
-        Args { variant, file }
+        Args {
+            variant,
+            source: Source::from_path(file),
+        }
+    }
+
+    /// Use the given text or bytes as input.
+    pub fn from_memory<B: Into<Vec<u8>>>(variant: Variant, raw: B) -> Self {
+        Args {
+            variant,
+            source: Source::Memory(raw.into()),
+        }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Variant {
     First,
     Second,
+    Both,
+}
+
+/// The solutions computed for a [`Variant`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solutions<S> {
+    First(S),
+    Second(S),
+    Both(S, S),
+}
+
+impl<S> Solutions<S> {
+    pub fn map<T, F>(self, mut f: F) -> Solutions<T>
+    where
+        F: FnMut(S) -> T,
+    {
+        match self {
+            Solutions::First(first) => Solutions::First(f(first)),
+            Solutions::Second(second) => Solutions::Second(f(second)),
+            Solutions::Both(first, second) => Solutions::Both(f(first), f(second)),
+        }
+    }
+
+    /// Iterate over the solved parts, labeled with [`Variant::First`] or [`Variant::Second`].
+    pub fn iter(&self) -> impl Iterator<Item = (Variant, &S)> {
+        let (first, second) = match self {
+            Solutions::First(first) => (Some(first), None),
+            Solutions::Second(second) => (None, Some(second)),
+            Solutions::Both(first, second) => (Some(first), Some(second)),
+        };
+        let first = first.map(|first| (Variant::First, first));
+        let second = second.map(|second| (Variant::Second, second));
+        first.into_iter().chain(second)
+    }
+}
+
+impl<S: std::fmt::Display> std::fmt::Display for Solutions<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Solutions::First(solution) | Solutions::Second(solution) => write!(f, "{solution}"),
+            Solutions::Both(first, second) => write!(f, "first: {first}\nsecond: {second}"),
+        }
+    }
+}
+
+impl std::str::FromStr for Variant {
+    type Err = Error;
+
+    fn from_str(raw: &str) -> Result<Self> {
+        match raw {
+            "1" | "first" => Ok(Variant::First),
+            "2" | "second" => Ok(Variant::Second),
+            "both" => Ok(Variant::Both),
+            _ => Err(Error::Usage(format!("Unknown variant {raw:?}"))),
+        }
+    }
+}
+
+impl std::fmt::Display for Variant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Variant::First => f.pad("first"),
+            Variant::Second => f.pad("second"),
+            Variant::Both => f.pad("both"),
+        }
+    }
 }
 
 impl Input for Args {
@@ -121,12 +373,10 @@ impl Input for Args {
     }
 }
 
-pub type Bytes = std::io::Bytes<BufReader<File>>;
+pub type Bytes = std::io::Bytes<Reader>;
 impl Input for Bytes {
     fn from_args(args: Args) -> Result<Self> {
-        let file = File::open(args.file)?;
-        let reader = BufReader::new(file);
-        Ok(std::io::Read::bytes(reader))
+        Ok(std::io::Read::bytes(args.source.open()?))
     }
 }
 
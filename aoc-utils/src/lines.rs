@@ -1,10 +1,6 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    marker::PhantomData,
-};
+use std::{io::BufRead, marker::PhantomData, rc::Rc};
 
-use crate::{Args, Input, Result};
+use crate::{Args, Error, Input, ParseError, Reader, Result};
 
 pub type InputLine = Result<String, std::io::Error>;
 
@@ -13,12 +9,14 @@ where
     T: From<InputLine>,
 {
     iter: Iter,
+    origin: Rc<str>,
+    number: usize,
     _t: PhantomData<T>,
 }
 
 pub enum Iter {
-    Stdin(std::io::Lines<std::io::StdinLock<'static>>),
-    File(std::io::Lines<BufReader<File>>),
+    Reader(std::io::Lines<Reader>),
+    Buffered(std::vec::IntoIter<String>),
 }
 
 impl<T> Iterator for Lines<T>
@@ -29,32 +27,66 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         let next = match &mut self.iter {
-            Iter::Stdin(iter) => iter.next(),
-            Iter::File(iter) => iter.next(),
+            Iter::Reader(iter) => iter.next(),
+            Iter::Buffered(iter) => iter.next().map(Ok),
         };
+        if next.is_some() {
+            self.number += 1;
+        }
         next.map(T::from)
     }
 }
 
+impl Lines {
+    /// Attach line numbers to the lines, to report where the input is malformed.
+    pub fn numbered(mut self) -> impl Iterator<Item = Result<NumberedLine>> {
+        std::iter::from_fn(move || {
+            let line = self.next()?.map(|text| NumberedLine {
+                text,
+                number: self.number,
+                origin: self.origin.clone(),
+            });
+            Some(line.map_err(Error::from))
+        })
+    }
+}
+
+impl<T> Lines<T>
+where
+    T: From<InputLine>,
+{
+    /// The number of the line returned last, starting at 1.
+    pub fn line_number(&self) -> usize {
+        self.number
+    }
+}
+
 impl<T> Input for Lines<T>
 where
     T: From<InputLine>,
 {
     fn from_args(args: Args) -> Result<Self> {
-        if args.file == "-" {
-            let stdin = std::io::stdin();
-            Ok(Lines {
-                iter: Iter::Stdin(stdin.lines()),
-                _t: PhantomData,
-            })
-        } else {
-            let file = File::open(args.file)?;
-            let reader = BufReader::new(file).lines();
-            Ok(Lines {
-                iter: Iter::File(reader),
-                _t: PhantomData,
-            })
-        }
+        Ok(Lines {
+            origin: args.source.name().into(),
+            iter: Iter::Reader(args.source.open()?.lines()),
+            number: 0,
+            _t: PhantomData,
+        })
+    }
+
+    fn from_args_twice(args: Args) -> Result<(Self, Self)> {
+        let origin: Rc<str> = args.source.name().into();
+        let lines = Lines::<InputLine>::from_args(args)?.collect::<Result<Vec<_>, _>>()?;
+        let buffered = |lines| Lines {
+            iter: Iter::Buffered(lines),
+            origin: origin.clone(),
+            number: 0,
+            _t: PhantomData,
+        };
+        Ok((
+            buffered(lines.clone().into_iter()),
+            buffered(lines.into_iter()),
+        ))
     }
 }
 
@@ -79,3 +111,87 @@ impl std::ops::Deref for RawLine {
         &self.0
     }
 }
+
+/// A line that knows where it came from, see [`Lines::numbered`].
+#[derive(Debug, Clone)]
+pub struct NumberedLine {
+    pub text: String,
+    /// The line number, starting at 1
+    pub number: usize,
+    origin: Rc<str>,
+}
+
+impl NumberedLine {
+    /// An error about the whole line.
+    pub fn error<S: Into<String>>(&self, why: S) -> Error {
+        self.error_at(0, self.text.len(), why)
+    }
+
+    /// An error about `part`, which has to be a slice of [`NumberedLine::text`].
+    ///
+    /// Falls back to the whole line if `part` lies elsewhere.
+    pub fn error_in<S: Into<String>>(&self, part: &str, why: S) -> Error {
+        let offset = (part.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        match offset.checked_add(part.len()) {
+            Some(end) if end <= self.text.len() => self.error_at(offset, part.len(), why),
+            _ => self.error(why),
+        }
+    }
+
+    /// An error about the `len` bytes starting at byte `offset`.
+    pub fn error_at<S: Into<String>>(&self, offset: usize, len: usize, why: S) -> Error {
+        let start = self
+            .text
+            .get(..offset)
+            .map_or(0, |prefix| prefix.chars().count());
+        let len = self
+            .text
+            .get(offset..offset + len)
+            .map_or(0, |part| part.chars().count());
+        Error::Parse(Box::new(ParseError {
+            origin: self.origin.to_string(),
+            line: self.number,
+            column: start + 1,
+            len,
+            text: self.text.clone(),
+            message: why.into(),
+        }))
+    }
+
+    /// Attach the position of this line to an [`Error::Input`].
+    pub fn locate(&self, why: Error) -> Error {
+        match why {
+            Error::Input(why) => self.error(why),
+            why => why,
+        }
+    }
+
+    /// Parse the whitespace separated numbers in `part`, a slice of [`NumberedLine::text`].
+    pub fn numbers<T>(&self, part: &str) -> Result<Vec<T>>
+    where
+        T: std::str::FromStr,
+        T::Err: std::fmt::Display,
+    {
+        part.split_ascii_whitespace()
+            .map(|raw| self.parse(raw))
+            .collect()
+    }
+
+    /// Parse `part`, a slice of [`NumberedLine::text`], reporting where it failed.
+    pub fn parse<T>(&self, part: &str) -> Result<T>
+    where
+        T: std::str::FromStr,
+        T::Err: std::fmt::Display,
+    {
+        part.parse()
+            .map_err(|why| self.error_in(part, format!("Invalid value {part:?}: {why}")))
+    }
+}
+
+impl std::ops::Deref for NumberedLine {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.text
+    }
+}
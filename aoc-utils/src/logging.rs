@@ -0,0 +1,35 @@
+//! Leveled diagnostics on stderr with [`tracing`].
+//!
+//! Solutions log through the re-exported macros, like
+//! `aoc_utils::tracing::debug!(steps, "found the loop")`, and stay silent
+//! unless a subscriber is installed with [`init`].
+
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::EnvFilter;
+
+/// Install a subscriber printing events to stderr.
+///
+/// Every `-v` raises the level from warnings over info and debug to trace.
+/// Without any, debug builds honour `RUST_LOG` while release builds only
+/// print warnings. Does nothing if a subscriber is installed already.
+pub fn init(verbosity: u8) {
+    let level = match verbosity {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    };
+    let filter = match verbosity {
+        0 if cfg!(debug_assertions) => EnvFilter::builder()
+            .with_default_directive(level.into())
+            .from_env_lossy(),
+        _ => EnvFilter::default().add_directive(level.into()),
+    };
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .without_time()
+        // Grids rendered for debugging bring their own colours
+        .with_ansi_sanitization(false)
+        .try_init();
+}
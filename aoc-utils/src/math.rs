@@ -0,0 +1,238 @@
+//! Number theory and lattice geometry.
+//!
+//! Every function that can overflow, so all but [`gcd`] and [`gcd_all`],
+//! has a `checked_` variant returning [`None`] where the plain one would panic.
+
+use std::ops::RangeInclusive;
+
+use crate::Idx2D;
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+/// The least common multiple, `0` if either number is `0`.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    checked_lcm(a, b).expect("lcm overflows u64")
+}
+
+pub fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    match gcd(a, b) {
+        0 => Some(0),
+        gcd => (a / gcd).checked_mul(b),
+    }
+}
+
+/// The greatest common divisor of all numbers, `0` if there are none.
+pub fn gcd_all<I: IntoIterator<Item = u64>>(numbers: I) -> u64 {
+    numbers.into_iter().fold(0, gcd)
+}
+
+/// The least common multiple of all numbers, `1` if there are none.
+pub fn lcm_all<I: IntoIterator<Item = u64>>(numbers: I) -> u64 {
+    checked_lcm_all(numbers).expect("lcm overflows u64")
+}
+
+pub fn checked_lcm_all<I: IntoIterator<Item = u64>>(numbers: I) -> Option<u64> {
+    numbers.into_iter().try_fold(1, checked_lcm)
+}
+
+/// Solve `x ≡ residue (mod modulus)` for all pairs at once with the Chinese
+/// remainder theorem, giving the smallest non-negative `x` and the modulus
+/// it repeats with.
+///
+/// The moduli need not be coprime. Gives [`None`] if the congruences
+/// contradict each other, and panics if the combined modulus overflows `i64`.
+pub fn crt<I: IntoIterator<Item = (i64, i64)>>(congruences: I) -> Option<(i64, i64)> {
+    try_crt(congruences).expect("crt modulus overflows i64")
+}
+
+/// Like [`crt`], but also [`None`] if the combined modulus overflows.
+pub fn checked_crt<I: IntoIterator<Item = (i64, i64)>>(congruences: I) -> Option<(i64, i64)> {
+    try_crt(congruences).flatten()
+}
+
+/// The outer [`None`] is an overflow, the inner one a contradiction.
+fn try_crt<I: IntoIterator<Item = (i64, i64)>>(congruences: I) -> Option<Option<(i64, i64)>> {
+    let mut solution = (0_i128, 1_i128);
+    for (residue, modulus) in congruences {
+        assert!(modulus > 0, "moduli have to be positive");
+        let (x, m) = solution;
+        let (residue, modulus) = (i128::from(residue), i128::from(modulus));
+        // x + m * k ≡ residue (mod modulus), solved for k
+        let (gcd, inverse, _) = ext_gcd(m, modulus);
+        let diff = residue - x;
+        if diff % gcd != 0 {
+            return Some(None);
+        }
+        let step = modulus / gcd;
+        let k = (diff / gcd % step * inverse).rem_euclid(step);
+        let combined = m * step;
+        if combined > i128::from(i64::MAX) {
+            return None;
+        }
+        solution = ((x + m * k).rem_euclid(combined), combined);
+    }
+    let (x, m) = solution;
+    Some(Some((x as i64, m as i64)))
+}
+
+/// The gcd of `a` and `b` with `x` and `y` such that `a * x + b * y == gcd`.
+fn ext_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    match b {
+        0 => (a, 1, 0),
+        _ => {
+            let (gcd, x, y) = ext_gcd(b, a.rem_euclid(b));
+            (gcd, y, x - a.div_euclid(b) * y)
+        }
+    }
+}
+
+/// All integers `x` with `a * x² + b * x + c < 0`, for a positive `a`.
+///
+/// The range is empty if there are none.
+pub fn quadratic_below_zero(a: i64, b: i64, c: i64) -> RangeInclusive<i64> {
+    checked_quadratic_below_zero(a, b, c).expect("quadratic overflows i64")
+}
+
+pub fn checked_quadratic_below_zero(a: i64, b: i64, c: i64) -> Option<RangeInclusive<i64>> {
+    assert!(a > 0, "the parabola has to open upwards");
+    let (a, b, c) = (i128::from(a), i128::from(b), i128::from(c));
+    let value = |x: i128| {
+        a.checked_mul(x)?
+            .checked_add(b)?
+            .checked_mul(x)?
+            .checked_add(c)
+    };
+    let discriminant = (b * b).checked_sub(4_i128.checked_mul(a)?.checked_mul(c)?)?;
+    // Without real roots the range stays empty
+    let (mut low, mut high) = (1, 0);
+    if discriminant > 0 {
+        // Start at the rounded real roots and walk to the exact integer bounds
+        let root = discriminant.unsigned_abs().isqrt() as i128;
+        (low, high) = ((-b - root).div_euclid(2 * a), (-b + root).div_euclid(2 * a));
+        while value(low)? >= 0 && low <= high {
+            low += 1;
+        }
+        while value(low - 1)? < 0 {
+            low -= 1;
+        }
+        while value(high)? >= 0 && high >= low {
+            high -= 1;
+        }
+        while value(high + 1)? < 0 {
+            high += 1;
+        }
+    }
+    Some(i64::try_from(low).ok()?..=i64::try_from(high).ok()?)
+}
+
+/// Twice the area enclosed by a polygon with the shoelace formula, which
+/// keeps it an integer.
+///
+/// The polygon is closed between the last and the first vertex.
+pub fn double_area<I: IntoIterator<Item = Idx2D<i64>>>(vertices: I) -> u64 {
+    checked_double_area(vertices).expect("area overflows i64")
+}
+
+pub fn checked_double_area<I: IntoIterator<Item = Idx2D<i64>>>(vertices: I) -> Option<u64> {
+    let mut vertices = vertices.into_iter();
+    let Some(first) = vertices.next() else {
+        return Some(0);
+    };
+    let mut sum = 0_i64;
+    let mut prev = first;
+    for curr in vertices.chain([first]) {
+        let cross = prev
+            .0
+            .checked_mul(curr.1)?
+            .checked_sub(curr.0.checked_mul(prev.1)?)?;
+        sum = sum.checked_add(cross)?;
+        prev = curr;
+    }
+    Some(sum.unsigned_abs())
+}
+
+/// The number of lattice points on the edges of a polygon.
+pub fn boundary_points<I: IntoIterator<Item = Idx2D<i64>>>(vertices: I) -> u64 {
+    checked_boundary_points(vertices).expect("boundary overflows u64")
+}
+
+pub fn checked_boundary_points<I: IntoIterator<Item = Idx2D<i64>>>(vertices: I) -> Option<u64> {
+    let mut vertices = vertices.into_iter();
+    let Some(first) = vertices.next() else {
+        return Some(0);
+    };
+    let mut sum = 0_u64;
+    let mut prev = first;
+    for curr in vertices.chain([first]) {
+        sum = sum.checked_add(gcd(prev.0.abs_diff(curr.0), prev.1.abs_diff(curr.1)))?;
+        prev = curr;
+    }
+    Some(sum)
+}
+
+/// The number of lattice points strictly inside a polygon with Pick's
+/// theorem, from its [`double_area`] and [`boundary_points`].
+pub fn interior_points(double_area: u64, boundary: u64) -> u64 {
+    checked_interior_points(double_area, boundary).expect("interior overflows u64")
+}
+
+pub fn checked_interior_points(double_area: u64, boundary: u64) -> Option<u64> {
+    Some(double_area.checked_add(2)?.checked_sub(boundary)? / 2)
+}
+
+/// The number of orthogonal steps between signed points, see
+/// [`search::manhattan`](crate::search::manhattan) for grid positions.
+pub fn manhattan_i64((a_x, a_y): Idx2D<i64>, (b_x, b_y): Idx2D<i64>) -> u64 {
+    checked_manhattan_i64((a_x, a_y), (b_x, b_y)).expect("distance overflows u64")
+}
+
+pub fn checked_manhattan_i64((a_x, a_y): Idx2D<i64>, (b_x, b_y): Idx2D<i64>) -> Option<u64> {
+    a_x.abs_diff(b_x).checked_add(a_y.abs_diff(b_y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn number_theory() {
+        assert_eq!(gcd_all([12, 18, 30]), 6);
+        assert_eq!(lcm_all([4, 6, 10]), 60);
+        assert_eq!(lcm_all([]), 1);
+        assert_eq!(checked_lcm_all([u64::MAX, 2]), None);
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(checked_crt([(0, i64::MAX), (1, 2)]), None);
+    }
+
+    #[test]
+    fn quadratic_bounds_are_exact() {
+        // Holding a boat's button for x of 30ms must beat 200mm: x * (30 - x) > 200
+        assert_eq!(quadratic_below_zero(1, -30, 200), 11..=19);
+        assert_eq!(quadratic_below_zero(1, -7, 9), 2..=5);
+        assert!(quadratic_below_zero(1, 0, 1).is_empty());
+        assert!(quadratic_below_zero(1, -2, 1).is_empty());
+        assert_eq!(quadratic_below_zero(2, 0, -8), -1..=1);
+        assert_eq!(checked_quadratic_below_zero(i64::MAX, 0, i64::MAX), None);
+        assert_eq!(checked_quadratic_below_zero(i64::MAX, 0, i64::MIN), None);
+        let roots = [0, 1, 15, 16, 17, u128::MAX].map(u128::isqrt);
+        assert_eq!(roots, [0, 1, 3, 4, 4, u64::MAX.into()]);
+    }
+
+    #[test]
+    fn lattice_polygons() {
+        let square = [(0, 0), (4, 0), (4, 4), (0, 4)];
+        assert_eq!(double_area(square), 32);
+        assert_eq!(boundary_points(square), 16);
+        assert_eq!(interior_points(32, 16), 9);
+        assert_eq!(double_area([(0, 0), (3, 0), (0, 3)]), 9);
+        assert_eq!(manhattan_i64((-2, 3), (4, -1)), 10);
+        assert_eq!(checked_double_area([(i64::MAX, 0), (0, i64::MAX)]), None);
+    }
+}
@@ -0,0 +1,141 @@
+//! Memoization for recursive functions, without global caches.
+//!
+//! A [`Memo`] is created by the caller and passed down the recursion, so it
+//! only lives as long as one computation. Keys can borrow from the input,
+//! like suffixes of a slice, which saves allocating a key for every call.
+//!
+//! ```ignore
+//! fn count<'a>(springs: &'a [u8], memo: &mut Memo<&'a [u8], usize>) -> usize {
+//!     memo.get_or_compute(springs, |memo| match springs {
+//!         [] => 1,
+//!         [_, rest @ ..] => count(rest, memo) + ...,
+//!     })
+//! }
+//! ```
+
+use std::{borrow::Borrow, collections::HashMap, fmt::Display, hash::Hash};
+
+/// A table of already computed results, see the [module docs](self).
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    table: HashMap<K, V>,
+    stats: Stats,
+}
+
+/// How often a [`Memo`] already knew the result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Memo {
+            table: HashMap::new(),
+            stats: Stats::default(),
+        }
+    }
+
+    /// Look up a result, by any borrowed form of the key.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let found = self.table.get(key).cloned();
+        match found {
+            Some(_) => self.stats.hits += 1,
+            None => self.stats.misses += 1,
+        }
+        found
+    }
+
+    /// Remember `value` for `key`, giving it back for convenience.
+    pub fn insert(&mut self, key: K, value: V) -> V {
+        self.table.insert(key, value.clone());
+        value
+    }
+
+    /// The result for `key`, calling `compute` with the memo to recurse if it is not known yet.
+    pub fn get_or_compute<F>(&mut self, key: K, compute: F) -> V
+    where
+        F: FnOnce(&mut Self) -> V,
+    {
+        match self.get(&key) {
+            Some(value) => value,
+            None => {
+                let value = compute(self);
+                self.insert(key, value)
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lookups = self.hits + self.misses;
+        let rate = match lookups {
+            0 => 0.0,
+            _ => self.hits as f64 * 100.0 / lookups as f64,
+        };
+        write!(
+            f,
+            "{} hits, {} misses ({rate:.1}% hit rate)",
+            self.hits, self.misses
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fib(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        match n {
+            0 | 1 => n,
+            _ => memo.get_or_compute(n, |memo| fib(n - 1, memo) + fib(n - 2, memo)),
+        }
+    }
+
+    #[test]
+    fn recursion_and_borrowed_keys() {
+        let mut memo = Memo::new();
+        assert_eq!(fib(80, &mut memo), 23_416_728_348_467_685);
+        assert_eq!(memo.len(), 79);
+        assert_eq!(
+            memo.stats(),
+            Stats {
+                hits: 77,
+                misses: 79
+            }
+        );
+
+        let mut owned: Memo<Vec<u8>, usize> = Memo::new();
+        owned.insert(b"#.?".to_vec(), 2);
+        assert_eq!(owned.get(&b"#.?"[..]), Some(2));
+        assert_eq!(owned.get(&b"#"[..]), None);
+        assert_eq!(
+            owned.stats().to_string(),
+            "1 hits, 1 misses (50.0% hit rate)"
+        );
+    }
+}
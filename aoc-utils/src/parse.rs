@@ -0,0 +1,111 @@
+//! Fallible helpers for the usual shapes of puzzle input.
+//!
+//! Every helper returns an [`Error::Input`] naming what was expected and the
+//! text it got instead. Use [`NumberedLine::locate`](crate::NumberedLine::locate)
+//! to add the position in the input.
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::{Error, Result};
+
+/// Parse a single value, ignoring surrounding whitespace.
+pub fn number<T>(raw: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let raw = raw.trim();
+    raw.parse()
+        .map_err(|why| Error::input(format!("Invalid number {raw:?}: {why}")))
+}
+
+/// Parse a whitespace separated list like `"0 3 -6 9"`.
+pub fn numbers<T>(raw: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    raw.split_ascii_whitespace().map(number).collect()
+}
+
+/// Parse every integer embedded in arbitrary text, including its sign.
+///
+/// `"x=-2, y=+15"` gives `[-2, 15]`.
+pub fn signed_numbers<T>(raw: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let bytes = raw.as_bytes();
+    let mut numbers = vec![];
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let start = idx;
+        if matches!(bytes[idx], b'-' | b'+') && bytes.get(idx + 1).is_some_and(u8::is_ascii_digit) {
+            idx += 1;
+        }
+        if !bytes[idx].is_ascii_digit() {
+            idx += 1;
+            continue;
+        }
+        while bytes.get(idx).is_some_and(u8::is_ascii_digit) {
+            idx += 1;
+        }
+        numbers.push(number(&raw[start..idx])?);
+    }
+    Ok(numbers)
+}
+
+/// Split `"key: value"` at the first `delimiter`, trimming both halves.
+pub fn key_value<'r>(raw: &'r str, delimiter: &str) -> Result<(&'r str, &'r str)> {
+    let (key, value) = raw
+        .split_once(delimiter)
+        .ok_or_else(|| Error::input(format!("Missing {delimiter:?} in {raw:?}")))?;
+    Ok((key.trim(), value.trim()))
+}
+
+/// Strip a `prefix` that has to be there.
+pub fn prefix<'r>(raw: &'r str, prefix: &str) -> Result<&'r str> {
+    raw.strip_prefix(prefix)
+        .ok_or_else(|| Error::input(format!("Expected {prefix:?} at the start of {raw:?}")))
+}
+
+/// Take the text between `open` and `close`, like the inside of `"(a, b)"`.
+pub fn enclosed<'r>(raw: &'r str, open: &str, close: &str) -> Result<&'r str> {
+    prefix(raw.trim(), open)?
+        .strip_suffix(close)
+        .ok_or_else(|| Error::input(format!("Expected {close:?} at the end of {raw:?}")))
+}
+
+/// Split into exactly `N` fields at `delimiter`, trimming each of them.
+pub fn fields<'r, const N: usize>(raw: &'r str, delimiter: &str) -> Result<[&'r str; N]> {
+    let fields: Vec<_> = raw.split(delimiter).map(str::trim).collect();
+    let found = fields.len();
+    fields.try_into().map_err(|_| {
+        Error::input(format!(
+            "Expected {N} fields separated by {delimiter:?}, found {found} in {raw:?}"
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn helpers_parse_and_reject() -> Result {
+        assert_eq!(numbers::<i8>(" 0 3 -6\t9 ")?, [0, 3, -6, 9]);
+        assert!(numbers::<u8>("1 -2").is_err());
+        assert_eq!(signed_numbers::<i32>("x=-2, y=+15: 3-4")?, [-2, 15, 3, -4]);
+        assert_eq!(key_value("Game 7: 3 blue", ":")?, ("Game 7", "3 blue"));
+        assert_eq!(prefix("Game 7", "Game ")?, "7");
+        assert_eq!(
+            prefix("Card 7", "Game ").unwrap_err().to_string(),
+            r#"Invalid input: Expected "Game " at the start of "Card 7""#
+        );
+        assert_eq!(enclosed(" (BBB, CCC)", "(", ")")?, "BBB, CCC");
+        assert_eq!(fields("BBB, CCC", ",")?, ["BBB", "CCC"]);
+        assert!(fields::<3>("BBB, CCC", ",").is_err());
+        Ok(())
+    }
+}
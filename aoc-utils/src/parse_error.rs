@@ -0,0 +1,72 @@
+/// Malformed input at a known position.
+///
+/// ```text
+/// inputs/inputs-04:3:8: Missing '|' delimiter
+///   |
+/// 3 | Card 3:  1 21 53 59 44  69 82 63 72 16
+///   |        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The file name, or `<stdin>` and `<memory>`
+    pub origin: String,
+    /// The line number, starting at 1
+    pub line: usize,
+    /// The column of the first offending character, starting at 1
+    pub column: usize,
+    /// The number of offending characters
+    pub len: usize,
+    /// The whole offending line
+    pub text: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ParseError {
+            origin,
+            line,
+            column,
+            len,
+            text,
+            message,
+        } = self;
+        let gutter = line.to_string().len();
+        writeln!(f, "{origin}:{line}:{column}: {message}")?;
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{line} | {text}")?;
+        write!(
+            f,
+            "{:gutter$} | {:offset$}{}",
+            "",
+            "",
+            "^".repeat((*len).max(1)),
+            offset = column - 1
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Args, Input, Lines, Variant};
+
+    #[test]
+    fn points_at_the_offending_text() -> crate::Result {
+        let args = Args::from_memory(Variant::First, "1 2\n3 x 4\n");
+        let errors: Vec<_> = Lines::from_args(args)?
+            .numbered()
+            .map(|line| {
+                let line = line?;
+                line.split(' ')
+                    .try_for_each(|raw| line.parse::<u8>(raw).map(drop))
+            })
+            .filter_map(|result| result.err())
+            .map(|why| why.to_string())
+            .collect();
+        assert_eq!(
+            errors,
+            ["<memory>:2:3: Invalid value \"x\": invalid digit found in string\n  |\n2 | 3 x 4\n  |   ^"]
+        );
+        Ok(())
+    }
+}
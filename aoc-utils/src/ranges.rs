@@ -0,0 +1,239 @@
+//! Arithmetic on sets of integers made of a few long ranges.
+//!
+//! Puzzles like mapping seed ranges or counting accepted part ratings work on
+//! far too many numbers to handle one by one, but only on few ranges of them.
+
+use std::ops::Range;
+
+/// A set of integers, kept as sorted, disjoint and non-touching ranges.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct RangeSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        RangeSet::default()
+    }
+
+    /// The ranges making up the set, in ascending order.
+    pub fn ranges(&self) -> &[Range<i64>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The total number of integers in the set.
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|range| range.start.abs_diff(range.end))
+            .sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.ranges.last().map(|range| range.end - 1)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let idx = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges
+            .get(idx)
+            .is_some_and(|range| range.contains(&value))
+    }
+
+    /// Add every integer in `range`.
+    pub fn insert(&mut self, range: Range<i64>) {
+        self.ranges.push(range);
+        self.normalize();
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let (mut ours, mut theirs) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        let mut ranges = vec![];
+        while let (Some(our), Some(their)) = (ours.peek(), theirs.peek()) {
+            let overlap = our.start.max(their.start)..our.end.min(their.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            // Drop whichever range ends first, it can not overlap anything else
+            match our.end < their.end {
+                true => ours.next(),
+                false => theirs.next(),
+            };
+        }
+        RangeSet { ranges }
+    }
+
+    /// The integers in `self` but not in `other`.
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = vec![];
+        let mut theirs = other.ranges.iter().peekable();
+        for our in &self.ranges {
+            let mut start = our.start;
+            while theirs.next_if(|their| their.end <= start).is_some() {}
+            for their in theirs.clone().take_while(|their| their.start < our.end) {
+                if their.start > start {
+                    ranges.push(start..their.start);
+                }
+                start = start.max(their.end);
+            }
+            if start < our.end {
+                ranges.push(start..our.end);
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    /// Split into the integers below `at` and the ones from `at` on.
+    pub fn split_at(&self, at: i64) -> (RangeSet, RangeSet) {
+        let below = RangeSet::from(i64::MIN..at);
+        (self.intersection(&below), self.difference(&below))
+    }
+
+    /// Move every integer by `offset`.
+    pub fn shifted(&self, offset: i64) -> RangeSet {
+        let ranges = self
+            .ranges
+            .iter()
+            .map(|range| range.start + offset..range.end + offset)
+            .collect();
+        RangeSet { ranges }
+    }
+
+    /// Sort, merge and drop empty ranges to restore the invariant.
+    fn normalize(&mut self) {
+        self.ranges.retain(|range| !range.is_empty());
+        self.ranges.sort_unstable_by_key(|range| range.start);
+        let mut merged: Vec<Range<i64>> = Vec::with_capacity(self.ranges.len());
+        for range in self.ranges.drain(..) {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        self.ranges = merged;
+    }
+}
+
+impl From<Range<i64>> for RangeSet {
+    fn from(range: Range<i64>) -> Self {
+        [range].into_iter().collect()
+    }
+}
+
+impl FromIterator<Range<i64>> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(iter: I) -> Self {
+        let mut set = RangeSet {
+            ranges: iter.into_iter().collect(),
+        };
+        set.normalize();
+        set
+    }
+}
+
+/// An `N`-dimensional box, with a range of integers along every axis.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeBox<const N: usize> {
+    pub axes: [Range<i64>; N],
+}
+
+impl<const N: usize> RangeBox<N> {
+    pub fn new(axes: [Range<i64>; N]) -> Self {
+        RangeBox { axes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(Range::is_empty)
+    }
+
+    /// The number of integer points inside.
+    pub fn volume(&self) -> u64 {
+        match self.is_empty() {
+            true => 0,
+            false => self
+                .axes
+                .iter()
+                .map(|range| range.start.abs_diff(range.end))
+                .product(),
+        }
+    }
+
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        self.axes
+            .iter()
+            .zip(point)
+            .all(|(range, val)| range.contains(&val))
+    }
+
+    pub fn intersection(&self, other: &RangeBox<N>) -> RangeBox<N> {
+        let axes = std::array::from_fn(|axis| {
+            let (ours, theirs) = (&self.axes[axis], &other.axes[axis]);
+            ours.start.max(theirs.start)..ours.end.min(theirs.end)
+        });
+        RangeBox { axes }
+    }
+
+    /// Cut along `axis` into the part below `at` and the part from `at` on,
+    /// either of which can be empty.
+    pub fn split_at(&self, axis: usize, at: i64) -> (RangeBox<N>, RangeBox<N>) {
+        let range = &self.axes[axis];
+        let at = at.clamp(range.start, range.end.max(range.start));
+        let (mut below, mut above) = (self.clone(), self.clone());
+        below.axes[axis].end = at;
+        above.axes[axis].start = at;
+        (below, above)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_operations() {
+        let set: RangeSet = [5..8, 0..3, 2..4, 8..9].into_iter().collect();
+        assert_eq!(set.ranges(), [0..4, 5..9]);
+        assert_eq!((set.len(), set.min(), set.max()), (8, Some(0), Some(8)));
+        assert!(set.contains(3) && !set.contains(4) && !set.contains(9));
+
+        let other: RangeSet = [3..6, 7..8, 20..21].into_iter().collect();
+        assert_eq!(set.union(&other).ranges(), [0..9, 20..21]);
+        assert_eq!(set.intersection(&other).ranges(), [3..4, 5..6, 7..8]);
+        assert_eq!(set.difference(&other).ranges(), [0..3, 6..7, 8..9]);
+        assert_eq!(other.difference(&set).ranges(), [4..5, 20..21]);
+
+        let (below, above) = set.split_at(6);
+        assert_eq!(below.ranges(), [0..4, 5..6]);
+        assert_eq!(above, RangeSet::from(6..9));
+        assert_eq!(set.shifted(-10).ranges(), [-10..-6, -5..-1]);
+        assert!(RangeSet::from(3..3).is_empty());
+    }
+
+    #[test]
+    fn boxes() {
+        let cube = RangeBox::new([1..4001, 1..4001, 1..4001]);
+        assert_eq!(cube.volume(), 64_000_000_000);
+        let (below, above) = cube.split_at(1, 1000);
+        assert_eq!(below.axes[1], 1..1000);
+        assert_eq!(below.volume() + above.volume(), cube.volume());
+        assert!(above.contains([1, 1000, 4000]) && !below.contains([1, 1000, 4000]));
+        let (empty, all) = cube.split_at(0, -5);
+        assert!(empty.is_empty());
+        assert_eq!(all, cube);
+        assert_eq!(below.intersection(&above).volume(), 0);
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{Grid, Idx2D};
+
+/// Orthogonally connected cells, see [`Grid::flood_fill`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Region {
+    /// The cells of the region, in the order they were reached.
+    pub cells: Vec<Idx2D>,
+    /// Whether any cell is in the outermost row or column of the grid.
+    pub touches_border: bool,
+}
+
+/// A grid split into regions, see [`Grid::components`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    /// The label of the region every cell belongs to, which is its index in `regions`.
+    pub labels: Grid<Option<usize>>,
+    pub regions: Vec<Region>,
+}
+
+impl Region {
+    pub fn size(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+impl Components {
+    pub fn label(&self, pos: Idx2D) -> Option<usize> {
+        self.labels[pos]
+    }
+
+    /// The region `pos` belongs to, if it matched the predicate.
+    pub fn region(&self, pos: Idx2D) -> Option<&Region> {
+        self.label(pos).map(|label| &self.regions[label])
+    }
+}
+
+impl<T> Grid<T> {
+    /// All cells reachable from `start` by orthogonal steps over `passable` cells.
+    ///
+    /// The region is empty if `start` itself is not passable.
+    pub fn flood_fill<F>(&self, start: Idx2D, passable: F) -> Region
+    where
+        F: Fn(Idx2D, &T) -> bool,
+    {
+        let mut seen = self.clone_with(false);
+        self.fill_from(start, &passable, &mut seen)
+    }
+
+    /// Split the cells matching `predicate` into orthogonally connected regions.
+    ///
+    /// Regions are labelled in the order of their first cell, row by row.
+    pub fn components<F>(&self, predicate: F) -> Components
+    where
+        F: Fn(Idx2D, &T) -> bool,
+    {
+        let mut seen = self.clone_with(false);
+        let mut labels = self.clone_with(None);
+        let mut regions = vec![];
+        for (pos, val) in self.iter_pos() {
+            if seen[pos] || !predicate(pos, val) {
+                continue;
+            }
+            let region = self.fill_from(pos, &predicate, &mut seen);
+            for &cell in &region.cells {
+                labels[cell] = Some(regions.len());
+            }
+            regions.push(region);
+        }
+        Components { labels, regions }
+    }
+
+    fn fill_from<F>(&self, start: Idx2D, passable: &F, seen: &mut Grid<bool>) -> Region
+    where
+        F: Fn(Idx2D, &T) -> bool,
+    {
+        let mut region = Region::default();
+        if !passable(start, &self[start]) {
+            return region;
+        }
+        seen[start] = true;
+        let mut queue = vec![start];
+        while let Some(pos @ (x, y)) = queue.pop() {
+            region.touches_border |=
+                x == 0 || y == 0 || x + 1 == self.width() || y + 1 == self.height();
+            region.cells.push(pos);
+            for (next, val) in self.neighbors4(pos) {
+                if !seen[next] && passable(next, val) {
+                    seen[next] = true;
+                    queue.push(next);
+                }
+            }
+        }
+        region
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Args, Grid, Input, Variant};
+
+    #[test]
+    fn regions_know_their_border() -> crate::Result {
+        let raw = ".....\n.###.\n.#.#.\n.###.\n.....\n";
+        let grid = Grid::<u8>::from_args(Args::from_memory(Variant::First, raw))?;
+        let open = |_, &cell: &u8| cell == b'.';
+        let outside = grid.flood_fill((0, 0), open);
+        assert_eq!((outside.size(), outside.touches_border), (16, true));
+        assert!(grid.flood_fill((1, 1), open).cells.is_empty());
+
+        let components = grid.components(open);
+        assert_eq!(components.regions.len(), 2);
+        assert_eq!(components.label((4, 4)), Some(0));
+        assert_eq!(components.label((1, 1)), None);
+        let inside = components.region((2, 2)).unwrap();
+        assert_eq!(
+            (inside.cells.as_slice(), inside.touches_border),
+            (&[(2, 2)][..], false)
+        );
+
+        let walls = grid.components(|_, &cell| cell == b'#');
+        assert_eq!(walls.regions.len(), 1);
+        assert_eq!(
+            (walls.regions[0].size(), walls.regions[0].touches_border),
+            (8, false)
+        );
+        Ok(())
+    }
+}
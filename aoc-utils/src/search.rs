@@ -0,0 +1,239 @@
+//! Shortest paths over any state type.
+//!
+//! The searches only need a start state, a closure listing the successors
+//! of a state and a closure recognizing the goal. States are deduplicated by
+//! [`Hash`] and [`Eq`], so a state should hold everything that decides where
+//! the search can go next, like the direction a crucible was moving in.
+//!
+//! ```ignore
+//! let found = search::dijkstra(start, |&pos| moves(pos), |&pos| pos == goal)?;
+//! println!("{} via {:?}", found.cost, found.path);
+//! ```
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::{Grid, Idx2D};
+
+/// The result of a successful search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<S, C> {
+    /// The total cost of all steps taken.
+    pub cost: C,
+    /// Every state visited, from the start to the goal, both inclusive.
+    pub path: Vec<S>,
+}
+
+/// Search with every step costing one.
+pub fn bfs<S, I, N, G>(start: S, mut successors: N, mut is_goal: G) -> Option<Found<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    let mut visited = Visited::new(start.clone());
+    let mut queue = VecDeque::from([(0, 0_usize)]);
+    while let Some((idx, cost)) = queue.pop_front() {
+        if is_goal(&visited.states[idx].0) {
+            return Some(visited.found(idx, cost));
+        }
+        for next in successors(&visited.states[idx].0) {
+            if let Some(next) = visited.insert(next, idx) {
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Search with each successor given together with the cost of stepping to it.
+pub fn dijkstra<S, C, I, N, G>(start: S, successors: N, is_goal: G) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+    N: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    a_star(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but guided by a `heuristic` estimating the remaining cost.
+///
+/// The heuristic must never overestimate, otherwise the path found might
+/// not be the cheapest one.
+pub fn a_star<S, C, I, N, H, G>(
+    start: S,
+    mut successors: N,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+    N: FnMut(&S) -> I,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut visited = Visited::new(start.clone());
+    let mut costs = vec![C::default()];
+    let mut open = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    while let Some(Reverse((_, cost, idx))) = open.pop() {
+        if cost > costs[idx] {
+            // A cheaper way to this state was found after this one was queued
+            continue;
+        }
+        if is_goal(&visited.states[idx].0) {
+            return Some(visited.found(idx, cost));
+        }
+        for (next, step) in successors(&visited.states[idx].0) {
+            let next_cost = cost + step;
+            let next_idx = match visited.index.get(&next) {
+                Some(&known) if costs[known] <= next_cost => continue,
+                Some(&known) => {
+                    visited.states[known].1 = idx;
+                    costs[known] = next_cost;
+                    known
+                }
+                None => {
+                    costs.push(next_cost);
+                    visited
+                        .insert(next, idx)
+                        .expect("state was not visited yet")
+                }
+            };
+            let estimate = next_cost + heuristic(&visited.states[next_idx].0);
+            open.push(Reverse((estimate, next_cost, next_idx)));
+        }
+    }
+    None
+}
+
+/// The states seen so far, each with the index of the state it was reached from.
+struct Visited<S> {
+    states: Vec<(S, usize)>,
+    index: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> {
+    fn new(start: S) -> Self {
+        Visited {
+            states: vec![(start.clone(), 0)],
+            index: HashMap::from([(start, 0)]),
+        }
+    }
+
+    /// Record `state` as reached from `from`, giving its index if it is new.
+    fn insert(&mut self, state: S, from: usize) -> Option<usize> {
+        match self.index.entry(state) {
+            Entry::Occupied(_) => None,
+            Entry::Vacant(entry) => {
+                self.states.push((entry.key().clone(), from));
+                entry.insert(self.states.len() - 1);
+                Some(self.states.len() - 1)
+            }
+        }
+    }
+
+    fn found<C>(&self, mut idx: usize, cost: C) -> Found<S, C> {
+        let mut path = vec![self.states[idx].0.clone()];
+        while idx != 0 {
+            idx = self.states[idx].1;
+            path.push(self.states[idx].0.clone());
+        }
+        path.reverse();
+        Found { cost, path }
+    }
+}
+
+/// The number of orthogonal steps between `a` and `b`.
+pub fn manhattan((a_x, a_y): Idx2D, (b_x, b_y): Idx2D) -> usize {
+    a_x.abs_diff(b_x) + a_y.abs_diff(b_y)
+}
+
+impl<T> Grid<T> {
+    /// The fewest orthogonal steps from `start` to `goal` over cells that are `passable`.
+    pub fn shortest_path<F>(
+        &self,
+        start: Idx2D,
+        goal: Idx2D,
+        passable: F,
+    ) -> Option<Found<Idx2D, usize>>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let successors = |&pos: &Idx2D| {
+            self.neighbors4(pos)
+                .filter(|(_, val)| passable(val))
+                .map(|(next, _)| next)
+                .collect::<Vec<_>>()
+        };
+        bfs(start, successors, |&pos| pos == goal)
+    }
+
+    /// The cheapest orthogonal path from `start` to `goal`, where entering a
+    /// cell costs `cost(cell)` and cells without a cost can not be entered.
+    pub fn cheapest_path<C, F>(&self, start: Idx2D, goal: Idx2D, cost: F) -> Option<Found<Idx2D, C>>
+    where
+        C: Copy + Ord + Default + Add<Output = C>,
+        F: Fn(&T) -> Option<C>,
+    {
+        let successors = |&pos: &Idx2D| {
+            self.neighbors4(pos)
+                .filter_map(|(next, val)| Some((next, cost(val)?)))
+                .collect::<Vec<_>>()
+        };
+        dijkstra(start, successors, |&pos| pos == goal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Args, Input, Variant};
+
+    fn grid(raw: &str) -> Grid<u8> {
+        Grid::from_args(Args::from_memory(Variant::First, raw)).unwrap()
+    }
+
+    #[test]
+    fn searches_agree_on_grids() {
+        let maze = grid("..#.\n#...\n..#.\n.#..\n");
+        let found = maze
+            .shortest_path((0, 0), (0, 3), |&cell| cell != b'#')
+            .unwrap();
+        assert_eq!(found.cost, 5);
+        assert_eq!(found.path.len(), 6);
+        assert_eq!(found.path.first(), Some(&(0, 0)));
+        assert_eq!(found.path.last(), Some(&(0, 3)));
+        assert!(maze
+            .shortest_path((0, 0), (2, 0), |&cell| cell != b'#')
+            .is_none());
+
+        let weights = grid("1911\n1919\n1119\n9911\n");
+        let cost = |&cell: &u8| Some((cell - b'0') as usize);
+        let found = weights.cheapest_path((0, 0), (3, 3), cost).unwrap();
+        assert_eq!(found.cost, 6);
+        assert_eq!(found.path[1..4], [(0, 1), (0, 2), (1, 2)]);
+        let goal = (3, 3);
+        let successors = |&pos: &Idx2D| {
+            weights
+                .neighbors4(pos)
+                .map(|(next, &cell)| (next, (cell - b'0') as usize))
+                .collect::<Vec<_>>()
+        };
+        let guided = a_star(
+            (0, 0),
+            successors,
+            |&pos| manhattan(pos, goal),
+            |&pos| pos == goal,
+        );
+        assert_eq!(guided, Some(found));
+    }
+}
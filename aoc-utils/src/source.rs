@@ -0,0 +1,120 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, Cursor, Read, StdinLock},
+};
+
+use crate::Result;
+
+/// Where the puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(String),
+    Stdin,
+    /// An input held in memory, like an example pasted into a test.
+    Memory(Vec<u8>),
+}
+
+/// An opened [`Source`].
+pub enum Reader {
+    File(BufReader<File>),
+    Stdin(StdinLock<'static>),
+    Memory(Cursor<Vec<u8>>),
+}
+
+impl Source {
+    /// Interpret a command line path, where `-` stands for stdin.
+    pub fn from_path(path: String) -> Self {
+        match path.as_str() {
+            "-" => Source::Stdin,
+            _ => Source::File(path),
+        }
+    }
+
+    /// How the source is called in error messages.
+    pub fn name(&self) -> String {
+        match self {
+            Source::File(path) => path.clone(),
+            Source::Stdin => String::from("<stdin>"),
+            Source::Memory(_) => String::from("<memory>"),
+        }
+    }
+
+    pub fn open(self) -> Result<Reader> {
+        Ok(match self {
+            Source::File(path) => Reader::File(BufReader::new(File::open(path)?)),
+            Source::Stdin => Reader::Stdin(std::io::stdin().lock()),
+            Source::Memory(raw) => Reader::Memory(Cursor::new(raw)),
+        })
+    }
+
+    /// Read the whole input, e.g. to parse it more than once.
+    pub fn read_all(self) -> Result<Vec<u8>> {
+        match self {
+            Source::Memory(raw) => Ok(raw),
+            source => {
+                let mut raw = vec![];
+                source.open()?.read_to_end(&mut raw)?;
+                Ok(raw)
+            }
+        }
+    }
+}
+
+impl Read for Reader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            Reader::File(reader) => reader.read(buf),
+            Reader::Stdin(reader) => reader.read(buf),
+            Reader::Memory(reader) => reader.read(buf),
+        }
+    }
+}
+
+impl BufRead for Reader {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        match self {
+            Reader::File(reader) => reader.fill_buf(),
+            Reader::Stdin(reader) => reader.fill_buf(),
+            Reader::Memory(reader) => reader.fill_buf(),
+        }
+    }
+
+    fn consume(&mut self, amt: usize) {
+        match self {
+            Reader::File(reader) => reader.consume(amt),
+            Reader::Stdin(reader) => reader.consume(amt),
+            Reader::Memory(reader) => reader.consume(amt),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Args, Bytes, Grid, Input, Lines, RawLine, Variant};
+
+    const EXAMPLE: &str = "12\n34\n";
+
+    #[test]
+    fn every_input_reads_from_memory() -> crate::Result {
+        let args = Args::from_memory(Variant::First, EXAMPLE);
+        let lines: Vec<_> = Lines::<RawLine>::from_args(args.clone())?
+            .map(RawLine::into_inner)
+            .collect();
+        assert_eq!(lines, ["12", "34"]);
+        let grid = Grid::<u8>::from_args(args.clone())?;
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[(1, 1)], b'4');
+        let bytes = Bytes::from_args(args)?.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(bytes, EXAMPLE.as_bytes());
+        Ok(())
+    }
+
+    #[test]
+    fn memory_inputs_can_be_read_twice() -> crate::Result {
+        let args = Args::from_memory(Variant::Both, EXAMPLE);
+        let (first, second) = Lines::<RawLine>::from_args_twice(args)?;
+        assert_eq!(first.count(), 2);
+        assert_eq!(second.count(), 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,257 @@
+use std::{collections::HashMap, fmt::Write as _};
+
+use crate::{Grid, Idx2D};
+
+/// An unbounded grid with signed coordinates, storing only the set cells.
+///
+/// The bounding box grows with every insert and shrinks again on removal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Idx2D<isize>, T>,
+    bounds: Option<(Idx2D<isize>, Idx2D<isize>)>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, pos: Idx2D<isize>) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Idx2D<isize>) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn contains(&self, pos: Idx2D<isize>) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn insert(&mut self, (x, y): Idx2D<isize>, val: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            None => ((x, y), (x, y)),
+            Some(((min_x, min_y), (max_x, max_y))) => {
+                ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y)))
+            }
+        });
+        self.cells.insert((x, y), val)
+    }
+
+    pub fn remove(&mut self, (x, y): Idx2D<isize>) -> Option<T> {
+        let removed = self.cells.remove(&(x, y))?;
+        if let Some(((min_x, min_y), (max_x, max_y))) = self.bounds {
+            if x == min_x || x == max_x || y == min_y || y == max_y {
+                self.bounds = Self::compute_bounds(self.cells.keys());
+            }
+        }
+        Some(removed)
+    }
+
+    /// Get the cell at `pos`, inserting the result of `f` if it is not set.
+    pub fn get_or_insert_with<F>(&mut self, pos: Idx2D<isize>, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        if !self.contains(pos) {
+            self.insert(pos, f());
+        }
+        self.cells.get_mut(&pos).expect("just inserted")
+    }
+
+    /// The top left and bottom right corner of all set cells, both inclusive.
+    pub fn bounds(&self) -> Option<(Idx2D<isize>, Idx2D<isize>)> {
+        self.bounds
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds
+            .map_or(0, |((min_x, _), (max_x, _))| max_x.abs_diff(min_x) + 1)
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds
+            .map_or(0, |((_, min_y), (_, max_y))| max_y.abs_diff(min_y) + 1)
+    }
+
+    /// The position one step in `dir` away, which always exists.
+    pub fn walk<D: Into<Idx2D<isize>>>(&self, (x, y): Idx2D<isize>, dir: D) -> Idx2D<isize> {
+        let (x_off, y_off) = dir.into();
+        (x + x_off, y + y_off)
+    }
+
+    /// All set cells, in no particular order.
+    pub fn iter_pos(&self) -> impl Iterator<Item = (Idx2D<isize>, &T)> {
+        self.cells.iter().map(|(pos, val)| (*pos, val))
+    }
+
+    /// Print every position inside the bounding box to stderr.
+    pub fn debug_render<F, S>(&self, f: F)
+    where
+        F: FnMut(Idx2D<isize>, Option<&T>) -> S,
+        S: std::fmt::Display,
+    {
+        eprint!("\n{}", self.render(f));
+    }
+
+    /// Render every position inside the bounding box, ending each row with a newline.
+    pub fn render<F, S>(&self, mut f: F) -> String
+    where
+        F: FnMut(Idx2D<isize>, Option<&T>) -> S,
+        S: std::fmt::Display,
+    {
+        let mut rendered = String::new();
+        for y in self.rows() {
+            for x in self.cols() {
+                write!(rendered, "{}", f((x, y), self.get((x, y)))).unwrap();
+            }
+            rendered.push('\n');
+        }
+        rendered
+    }
+
+    /// Convert into a dense grid covering the bounding box, filling unset cells with `fill`.
+    ///
+    /// The top left cell of the grid is the first corner of [`SparseGrid::bounds`].
+    pub fn into_grid(mut self, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let rows: Vec<Vec<T>> = self
+            .rows()
+            .map(|y| {
+                self.cols()
+                    .map(|x| self.cells.remove(&(x, y)).unwrap_or_else(|| fill.clone()))
+                    .collect()
+            })
+            .collect();
+        Grid::from_rows(rows).expect("rows of the bounding box have equal length")
+    }
+
+    fn rows(&self) -> std::ops::RangeInclusive<isize> {
+        match self.bounds {
+            Some(((_, min_y), (_, max_y))) => min_y..=max_y,
+            #[allow(clippy::reversed_empty_ranges)]
+            None => 0..=-1,
+        }
+    }
+
+    fn cols(&self) -> std::ops::RangeInclusive<isize> {
+        match self.bounds {
+            Some(((min_x, _), (max_x, _))) => min_x..=max_x,
+            #[allow(clippy::reversed_empty_ranges)]
+            None => 0..=-1,
+        }
+    }
+
+    fn compute_bounds<'p, I>(positions: I) -> Option<(Idx2D<isize>, Idx2D<isize>)>
+    where
+        I: Iterator<Item = &'p Idx2D<isize>>,
+    {
+        positions.fold(None, |bounds, &(x, y)| {
+            Some(match bounds {
+                None => ((x, y), (x, y)),
+                Some(((min_x, min_y), (max_x, max_y))) => {
+                    ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y)))
+                }
+            })
+        })
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(Idx2D<isize>, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Idx2D<isize>, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        iter.into_iter().for_each(|(pos, val)| {
+            grid.insert(pos, val);
+        });
+        grid
+    }
+}
+
+impl<T> From<Grid<T>> for SparseGrid<T> {
+    /// Every cell of the dense grid becomes a set cell at the same position.
+    fn from(grid: Grid<T>) -> Self {
+        let width = grid.width();
+        grid.inner
+            .into_iter()
+            .enumerate()
+            .map(|(idx, val)| (((idx % width) as isize, (idx / width) as isize), val))
+            .collect()
+    }
+}
+
+/// Unset cells are shown as `.`.
+impl<T: std::fmt::Display> std::fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in self.rows() {
+            writeln!(f)?;
+            for x in self.cols() {
+                match self.get((x, y)) {
+                    Some(val) => write!(f, "{val}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+        }
+        writeln!(f)
+    }
+}
+
+impl<T> std::ops::Index<Idx2D<isize>> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Idx2D<isize>) -> &Self::Output {
+        self.get(pos).expect("no cell at this position")
+    }
+}
+
+impl<T> std::ops::IndexMut<Idx2D<isize>> for SparseGrid<T> {
+    fn index_mut(&mut self, pos: Idx2D<isize>) -> &mut Self::Output {
+        self.get_mut(pos).expect("no cell at this position")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Direction, SparseGrid};
+
+    #[test]
+    fn grows_shrinks_and_converts() {
+        let mut sparse = SparseGrid::new();
+        let mut pos = (0, 0);
+        for dir in [Direction::West, Direction::North, Direction::North] {
+            sparse.insert(pos, '#');
+            pos = sparse.walk(pos, dir);
+        }
+        sparse.insert(pos, 'x');
+        assert_eq!(sparse.bounds(), Some(((-1, -2), (0, 0))));
+        assert_eq!(sparse.to_string(), "\nx.\n#.\n##\n");
+        assert_eq!(sparse.remove((-1, -2)), Some('x'));
+        assert_eq!((sparse.width(), sparse.height()), (2, 2));
+        let grid = sparse.clone().into_grid(' ');
+        assert_eq!(grid.to_string(), "\n# \n##\n");
+        assert_eq!(SparseGrid::from(grid).len(), 4);
+        assert!(SparseGrid::<char>::new()
+            .into_grid(' ')
+            .iter_pos()
+            .next()
+            .is_none());
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{Direction, Direction8, Grid, Idx2D};
+
+impl<T> Grid<T> {
+    /// Look at the grid repeated forever in every direction.
+    ///
+    /// The copy at tile `(0, 0)` is the grid itself, so non-negative positions
+    /// inside it keep their meaning.
+    ///
+    /// # Panics
+    ///
+    /// If the grid is empty, as there is nothing to repeat.
+    pub fn tiled(&self) -> TiledView<'_, T> {
+        assert!(!self.inner.is_empty(), "an empty grid can not be tiled");
+        TiledView { grid: self }
+    }
+}
+
+/// An infinite view repeating a [`Grid`], see [`Grid::tiled`].
+pub struct TiledView<'g, T> {
+    grid: &'g Grid<T>,
+}
+
+impl<T> Clone for TiledView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TiledView<'_, T> {}
+
+impl<'g, T> TiledView<'g, T> {
+    /// The width of a single tile.
+    pub fn width(&self) -> usize {
+        self.grid.width()
+    }
+
+    /// The height of a single tile.
+    pub fn height(&self) -> usize {
+        self.grid.height()
+    }
+
+    pub fn get(&self, pos: Idx2D<isize>) -> &'g T {
+        &self.grid[self.grid_pos(pos)]
+    }
+
+    /// Which copy of the grid `pos` falls in, with `(0, 0)` being the original.
+    pub fn tile(&self, (x, y): Idx2D<isize>) -> Idx2D<isize> {
+        (
+            x.div_euclid(self.width() as isize),
+            y.div_euclid(self.height() as isize),
+        )
+    }
+
+    /// The position in the underlying grid.
+    pub fn grid_pos(&self, (x, y): Idx2D<isize>) -> Idx2D {
+        (
+            x.rem_euclid(self.width() as isize) as usize,
+            y.rem_euclid(self.height() as isize) as usize,
+        )
+    }
+
+    /// The position one step in `dir` away, which always exists.
+    pub fn walk<D: Into<Idx2D<isize>>>(&self, (x, y): Idx2D<isize>, dir: D) -> Idx2D<isize> {
+        let (x_off, y_off) = dir.into();
+        (x + x_off, y + y_off)
+    }
+
+    /// The orthogonal neighbors of `pos`, crossing into the next tile at the edges.
+    pub fn neighbors4(&self, pos: Idx2D<isize>) -> impl Iterator<Item = (Idx2D<isize>, &'g T)> {
+        self.neighbors_with(pos, Direction::ALL)
+            .map(|(_, pos, val)| (pos, val))
+    }
+
+    /// The orthogonal and diagonal neighbors of `pos`.
+    pub fn neighbors8(&self, pos: Idx2D<isize>) -> impl Iterator<Item = (Idx2D<isize>, &'g T)> {
+        self.neighbors_with(pos, Direction8::ALL)
+            .map(|(_, pos, val)| (pos, val))
+    }
+
+    /// The neighbors of `pos` in the given directions, with the direction taken.
+    pub fn neighbors_with<D, I>(
+        &self,
+        pos: Idx2D<isize>,
+        dirs: I,
+    ) -> impl Iterator<Item = (D, Idx2D<isize>, &'g T)>
+    where
+        D: Into<Idx2D<isize>> + Copy,
+        I: IntoIterator<Item = D>,
+    {
+        let view = *self;
+        dirs.into_iter().map(move |dir| {
+            let next = view.walk(pos, dir);
+            (dir, next, view.get(next))
+        })
+    }
+}
+
+impl<T> std::ops::Index<Idx2D<isize>> for TiledView<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: Idx2D<isize>) -> &Self::Output {
+        self.get(pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{Args, Grid, Input, Variant};
+
+    const GARDEN: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+";
+
+    #[test]
+    fn walking_across_tiles() -> crate::Result {
+        let grid = Grid::<u8>::from_args(Args::from_memory(Variant::First, GARDEN))?;
+        let tiled = grid.tiled();
+        assert_eq!(tiled[(-1, -1)], grid[(10, 10)]);
+        assert_eq!(tiled.tile((-1, 11)), (-1, 1));
+        assert_eq!(tiled.grid_pos((-12, 23)), (10, 1));
+
+        let (x, y) = grid.find_idx(|&cell| cell == b'S').unwrap();
+        let mut reached = HashSet::from([(x as isize, y as isize)]);
+        let mut counts = vec![];
+        for _ in 0..50 {
+            reached = reached
+                .iter()
+                .flat_map(|&pos| tiled.neighbors4(pos))
+                .filter(|&(_, &cell)| cell != b'#')
+                .map(|(pos, _)| pos)
+                .collect();
+            counts.push(reached.len());
+        }
+        assert_eq!([counts[5], counts[9], counts[49]], [16, 50, 1594]);
+        Ok(())
+    }
+}
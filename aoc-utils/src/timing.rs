@@ -0,0 +1,60 @@
+use std::time::{Duration, Instant};
+
+/// Durations of the phases of repeated runs.
+///
+/// Streaming inputs like [`Lines`](crate::Lines) are only opened when parsing
+/// and read while solving, which is accounted to the solver.
+#[derive(Debug, Default, Clone)]
+pub struct Timings {
+    pub parse: Samples,
+    pub first: Samples,
+    pub second: Samples,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Samples(Vec<Duration>);
+
+impl Samples {
+    /// Run `f` and record how long it took.
+    pub fn measure<T, F>(&mut self, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        let start = Instant::now();
+        let result = f();
+        self.0.push(start.elapsed());
+        result
+    }
+
+    pub fn min(&self) -> Option<Duration> {
+        self.0.iter().min().copied()
+    }
+
+    pub fn median(&self) -> Option<Duration> {
+        let mut sorted = self.0.clone();
+        sorted.sort_unstable();
+        sorted.get(sorted.len() / 2).copied()
+    }
+
+    pub fn max(&self) -> Option<Duration> {
+        self.0.iter().max().copied()
+    }
+}
+
+impl std::fmt::Display for Timings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:<8}{:>12}{:>12}{:>12}", "", "min", "median", "max")?;
+        for (name, samples) in [
+            ("parse", &self.parse),
+            ("first", &self.first),
+            ("second", &self.second),
+        ] {
+            if let (Some(min), Some(median), Some(max)) =
+                (samples.min(), samples.median(), samples.max())
+            {
+                write!(f, "\n{name:<8}{min:>12.2?}{median:>12.2?}{max:>12.2?}")?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,305 @@
+use crate::{Direction, Grid, Idx2D};
+
+impl<T> Grid<T> {
+    /// Mirror along the main diagonal, so rows become columns.
+    pub fn transpose(&mut self) {
+        let (width, height) = (self.width, self.height());
+        self.permute(height, |(x, y)| (y, x));
+        debug_assert_eq!(self.width(), height);
+        debug_assert_eq!(self.height(), width);
+    }
+
+    pub fn rotate_cw(&mut self) {
+        self.transpose();
+        self.flip_horizontal();
+    }
+
+    pub fn rotate_ccw(&mut self) {
+        self.transpose();
+        self.flip_vertical();
+    }
+
+    /// Mirror left and right.
+    pub fn flip_horizontal(&mut self) {
+        if self.width > 0 {
+            self.inner
+                .chunks_mut(self.width)
+                .for_each(|row| row.reverse());
+        }
+    }
+
+    /// Mirror top and bottom.
+    pub fn flip_vertical(&mut self) {
+        let height = self.height();
+        for y in 0..height / 2 {
+            let (upper, lower) = self.inner.split_at_mut((height - 1 - y) * self.width);
+            upper[y * self.width..(y + 1) * self.width].swap_with_slice(&mut lower[..self.width]);
+        }
+    }
+
+    /// Exchange the rows `a` and `b`, panicking if either is out of bounds.
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        let (a, b) = (a.min(b), a.max(b));
+        assert!(b < self.height(), "row {b} is out of bounds");
+        if a != b {
+            let (upper, lower) = self.inner.split_at_mut(b * self.width);
+            upper[a * self.width..(a + 1) * self.width].swap_with_slice(&mut lower[..self.width]);
+        }
+    }
+
+    /// Exchange the columns `a` and `b`, panicking if either is out of bounds.
+    pub fn swap_cols(&mut self, a: usize, b: usize) {
+        assert!(
+            a.max(b) < self.width,
+            "column {} is out of bounds",
+            a.max(b)
+        );
+        if self.width > 0 {
+            self.inner
+                .chunks_mut(self.width)
+                .for_each(|row| row.swap(a, b));
+        }
+    }
+
+    pub fn transposed(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(Self::transpose)
+    }
+
+    pub fn rotated_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(Self::rotate_cw)
+    }
+
+    pub fn rotated_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(Self::rotate_ccw)
+    }
+
+    pub fn flipped_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(Self::flip_horizontal)
+    }
+
+    pub fn flipped_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(Self::flip_vertical)
+    }
+
+    /// Look at the grid turned so that `up` faces up, without copying it.
+    pub fn view(&self, up: Direction) -> GridView<'_, T> {
+        GridView { grid: self, up }
+    }
+
+    /// Like [`Grid::view`], but the cells can be changed through the view.
+    pub fn view_mut(&mut self, up: Direction) -> GridViewMut<'_, T> {
+        GridViewMut { grid: self, up }
+    }
+
+    fn transformed(&self, transform: fn(&mut Self)) -> Self
+    where
+        T: Clone,
+    {
+        let mut grid = self.clone();
+        transform(&mut grid);
+        grid
+    }
+
+    /// Rearrange the cells so the grid gets `width` and the cell at a new
+    /// position is taken from `source(new_pos)`.
+    fn permute<F>(&mut self, width: usize, source: F)
+    where
+        F: Fn(Idx2D) -> Idx2D,
+    {
+        let old_width = self.width;
+        let src = |idx: usize| {
+            let (x, y) = source((idx % width, idx / width));
+            x + y * old_width
+        };
+        // Follow every cycle of the permutation, swapping the cells into place
+        let mut done = vec![false; self.inner.len()];
+        for start in 0..self.inner.len() {
+            if done[start] {
+                continue;
+            }
+            let mut curr = start;
+            loop {
+                done[curr] = true;
+                let next = src(curr);
+                if next == start {
+                    break;
+                }
+                self.inner.swap(curr, next);
+                curr = next;
+            }
+        }
+        self.width = width;
+    }
+}
+
+/// A rotated view on a [`Grid`], see [`Grid::view`].
+pub struct GridView<'g, T> {
+    grid: &'g Grid<T>,
+    up: Direction,
+}
+
+/// A rotated mutable view on a [`Grid`], see [`Grid::view_mut`].
+pub struct GridViewMut<'g, T> {
+    grid: &'g mut Grid<T>,
+    up: Direction,
+}
+
+/// Where the cell at `(x, y)` in a view with `up` facing up is in a grid of `width` by `height`.
+fn grid_pos(up: Direction, width: usize, height: usize, (x, y): Idx2D) -> Idx2D {
+    match up {
+        Direction::North => (x, y),
+        Direction::East => (width - 1 - y, x),
+        Direction::South => (width - 1 - x, height - 1 - y),
+        Direction::West => (y, height - 1 - x),
+    }
+}
+
+macro_rules! view_impl {
+    ($view:ident) => {
+        impl<T> $view<'_, T> {
+            pub fn width(&self) -> usize {
+                match self.up.is_vertical() {
+                    true => self.grid.width(),
+                    false => self.grid.height(),
+                }
+            }
+
+            pub fn height(&self) -> usize {
+                match self.up.is_vertical() {
+                    true => self.grid.height(),
+                    false => self.grid.width(),
+                }
+            }
+
+            /// The position in the underlying grid.
+            pub fn grid_pos(&self, pos: Idx2D) -> Idx2D {
+                grid_pos(self.up, self.grid.width(), self.grid.height(), pos)
+            }
+
+            pub fn row(&self, y: usize) -> impl Iterator<Item = &T> + '_ {
+                (0..self.width()).map(move |x| &self[(x, y)])
+            }
+
+            pub fn col(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+                (0..self.height()).map(move |y| &self[(x, y)])
+            }
+
+            /// Copy the view into a new grid.
+            pub fn to_grid(&self) -> Grid<T>
+            where
+                T: Clone,
+            {
+                let rows = (0..self.height()).map(|y| self.row(y).cloned());
+                Grid::from_rows(rows).expect("rows of a view have equal length")
+            }
+        }
+
+        impl<T> std::ops::Index<Idx2D> for $view<'_, T> {
+            type Output = T;
+
+            fn index(&self, pos: Idx2D) -> &Self::Output {
+                &self.grid[self.grid_pos(pos)]
+            }
+        }
+    };
+}
+
+view_impl!(GridView);
+view_impl!(GridViewMut);
+
+impl<T> GridViewMut<'_, T> {
+    /// Exchange the rows `a` and `b` of the view.
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        for x in 0..self.width() {
+            self.swap((x, a), (x, b));
+        }
+    }
+
+    /// Exchange the columns `a` and `b` of the view.
+    pub fn swap_cols(&mut self, a: usize, b: usize) {
+        for y in 0..self.height() {
+            self.swap((a, y), (b, y));
+        }
+    }
+
+    fn swap(&mut self, first: Idx2D, second: Idx2D) {
+        let width = self.grid.width();
+        let ((a_x, a_y), (b_x, b_y)) = (self.grid_pos(first), self.grid_pos(second));
+        self.grid.inner.swap(a_x + a_y * width, b_x + b_y * width);
+    }
+}
+
+impl<T> std::ops::IndexMut<Idx2D> for GridViewMut<'_, T> {
+    fn index_mut(&mut self, pos: Idx2D) -> &mut Self::Output {
+        let pos = self.grid_pos(pos);
+        &mut self.grid[pos]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Direction, Grid};
+
+    fn grid(rows: &[&str]) -> Grid<char> {
+        Grid::from_rows(rows.iter().map(|row| row.chars())).unwrap()
+    }
+
+    #[test]
+    fn transforms_match_views() {
+        let original = grid(&["abc", "def"]);
+        let cases = [
+            (Direction::North, original.clone()),
+            (Direction::East, original.rotated_ccw()),
+            (Direction::South, original.rotated_cw().rotated_cw()),
+            (Direction::West, original.rotated_cw()),
+        ];
+        for (up, rotated) in cases {
+            assert_eq!(original.view(up).to_grid(), rotated, "{up:?}");
+        }
+        assert_eq!(original.rotated_cw(), grid(&["da", "eb", "fc"]));
+        assert_eq!(original.transposed(), grid(&["ad", "be", "cf"]));
+        assert_eq!(original.flipped_horizontal(), grid(&["cba", "fed"]));
+        assert_eq!(original.flipped_vertical(), grid(&["def", "abc"]));
+        assert_eq!(original.rotated_cw().rotated_ccw(), original);
+    }
+
+    #[test]
+    fn views_write_through() {
+        let mut grid = grid(&["abc", "def"]);
+        let mut view = grid.view_mut(Direction::West);
+        assert_eq!((view.width(), view.height()), (2, 3));
+        view[(0, 0)] = '*';
+        assert_eq!(grid.to_string(), "\nabc\n*ef\n");
+    }
+
+    #[test]
+    fn swaps() {
+        let mut original = grid(&["abc", "def", "ghi"]);
+        original.swap_rows(2, 0);
+        assert_eq!(original, grid(&["ghi", "def", "abc"]));
+        original.swap_cols(0, 1);
+        assert_eq!(original, grid(&["hgi", "edf", "bac"]));
+        original.swap_rows(1, 1);
+        assert_eq!(original, grid(&["hgi", "edf", "bac"]));
+
+        let mut view = original.view_mut(Direction::East);
+        view.swap_rows(0, 2);
+        view.swap_cols(0, 1);
+        assert_eq!(original, grid(&["fde", "igh", "cab"]));
+    }
+}
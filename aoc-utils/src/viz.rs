@@ -0,0 +1,139 @@
+//! Coloured grid rendering for the terminal, only built with the `viz` feature.
+//!
+//! A [`Styled`] rendering stacks layers of styles on top of [`Grid::render`],
+//! like a colour map for the cell values and highlights for a path, and can
+//! explain the symbols in a legend below the grid.
+//!
+//! ```ignore
+//! let rendered = grid
+//!     .styled()
+//!     .color_map(colorous::BLUES, |_, heat| f64::from(*heat) / 9.0)
+//!     .highlight(path, |style| style.bg(Color::Red))
+//!     .legend(' ', Style::default().bg(Color::Red), "path")
+//!     .render(|_, heat| heat);
+//! ```
+
+use std::{collections::HashSet, fmt::Display, fmt::Write as _};
+
+pub use colorous::{self, Gradient};
+pub use yansi::{Color, Paint, Style};
+
+use crate::{Grid, Idx2D};
+
+type Layer<'g, T> = Box<dyn Fn(Idx2D, &T, Style) -> Style + 'g>;
+
+/// A rendering of a grid with styled cells, see the [module docs](self).
+pub struct Styled<'g, T> {
+    grid: &'g Grid<T>,
+    layers: Vec<Layer<'g, T>>,
+    legend: Vec<(String, Style, String)>,
+}
+
+impl<T> Grid<T> {
+    /// Start a [`Styled`] rendering without any styles.
+    pub fn styled(&self) -> Styled<'_, T> {
+        Styled {
+            grid: self,
+            layers: vec![],
+            legend: vec![],
+        }
+    }
+}
+
+impl<'g, T> Styled<'g, T> {
+    /// Restyle every cell with `f`, getting the style of the layers added before.
+    pub fn style_with<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Idx2D, &T, Style) -> Style + 'g,
+    {
+        self.layers.push(Box::new(f));
+        self
+    }
+
+    /// Colour the foreground along `gradient`, with `value` between `0.0` and `1.0`.
+    pub fn color_map<F>(self, gradient: Gradient, value: F) -> Self
+    where
+        F: Fn(Idx2D, &T) -> f64 + 'g,
+    {
+        self.style_with(move |pos, cell, style| {
+            let color = gradient.eval_continuous(value(pos, cell).clamp(0.0, 1.0));
+            style.fg(Color::RGB(color.r, color.g, color.b))
+        })
+    }
+
+    /// Restyle the cells at `positions`, like a path or a region.
+    pub fn highlight<I, F>(self, positions: I, restyle: F) -> Self
+    where
+        I: IntoIterator<Item = Idx2D>,
+        F: Fn(Style) -> Style + 'g,
+    {
+        let positions: HashSet<_> = positions.into_iter().collect();
+        self.style_with(move |pos, _, style| match positions.contains(&pos) {
+            true => restyle(style),
+            false => style,
+        })
+    }
+
+    /// Explain what a `symbol` in the given `style` means, below the grid.
+    pub fn legend<S: Display, D: Display>(
+        mut self,
+        symbol: S,
+        style: Style,
+        description: D,
+    ) -> Self {
+        self.legend
+            .push((symbol.to_string(), style, description.to_string()));
+        self
+    }
+
+    /// Render every cell with `draw` in its style, followed by the legend.
+    pub fn render<F, S>(&self, mut draw: F) -> String
+    where
+        F: FnMut(Idx2D, &T) -> S,
+        S: Display,
+    {
+        let mut rendered = self.grid.render(|pos, cell| {
+            let style = self
+                .layers
+                .iter()
+                .fold(Style::default(), |style, layer| layer(pos, cell, style));
+            style.paint(draw(pos, cell))
+        });
+        for (symbol, style, description) in &self.legend {
+            writeln!(rendered, "{} {description}", style.paint(symbol)).unwrap();
+        }
+        rendered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Args, Input, Variant};
+
+    #[test]
+    fn layers_stack_up() -> crate::Result {
+        let grid = Grid::<u8>::from_args(Args::from_memory(Variant::First, "ab\ncd\n"))?;
+        let plain = grid.styled().render(|_, &cell| cell as char);
+        assert_eq!(plain, "ab\ncd\n");
+
+        let rendered = grid
+            .styled()
+            .color_map(colorous::GREYS, |(x, _), _| x as f64)
+            .highlight([(1, 1)], |style| style.bold())
+            .legend('d', Style::default().bold(), "highlighted")
+            .render(|_, &cell| cell as char);
+        let white = Style::new(Color::RGB(255, 255, 255));
+        let black = Style::new(Color::RGB(0, 0, 0));
+        let expected = format!(
+            "{}{}\n{}{}\n{} highlighted\n",
+            white.paint('a'),
+            black.paint('b'),
+            white.paint('c'),
+            black.bold().paint('d'),
+            Style::default().bold().paint('d'),
+        );
+        assert_eq!(rendered, expected);
+        Ok(())
+    }
+}
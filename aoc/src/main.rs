@@ -0,0 +1,163 @@
+use std::{ops::RangeInclusive, panic::catch_unwind, str::FromStr};
+
+use aoc_utils::{Args, Error, Ledger, Result, Variant};
+use clap::{ArgAction, Parser, Subcommand};
+
+mod registry;
+
+use registry::{Solver, SOLVERS};
+
+#[derive(Debug, Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// List all days with a registered solution
+    List,
+    /// Solve one or more days
+    Run(Run),
+}
+
+#[derive(Debug, clap::Args)]
+struct Run {
+    /// A single day (`14`), an inclusive range (`1-14`) or `all`
+    days: Days,
+    /// The puzzle to solve: 1, 2 or both (default)
+    variant: Option<Variant>,
+    /// The input file, defaults to `inputs/inputs-XX`
+    input: Option<String>,
+    /// Repeat every run and print how long parsing and solving took
+    #[arg(long, value_name = "RUNS", num_args = 0..=1, default_missing_value = "10")]
+    time: Option<usize>,
+    /// Check solutions against the answers recorded in this file
+    #[arg(long, value_name = "PATH", default_value = "answers.toml")]
+    answers: String,
+    /// Log diagnostics to stderr, repeat for more detail
+    #[arg(short, long, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Debug, Clone)]
+struct Days(RangeInclusive<u8>);
+
+fn main() -> Result {
+    match Cli::parse().command {
+        Command::List => {
+            SOLVERS
+                .iter()
+                .for_each(|solver| println!("{:02} {}", solver.day, solver.name));
+            Ok(())
+        }
+        Command::Run(command) => run(command),
+    }
+}
+
+fn run(
+    Run {
+        days,
+        variant,
+        input,
+        time,
+        answers,
+        verbose,
+    }: Run,
+) -> Result {
+    aoc_utils::logging::init(verbose);
+    let solvers: Vec<&Solver> = SOLVERS
+        .iter()
+        .filter(|solver| days.0.contains(&solver.day))
+        .collect();
+    if solvers.is_empty() {
+        return Err(Error::Other(format!("No solution registered for {days}")));
+    }
+    if input.is_some() && solvers.len() > 1 {
+        return Err(Error::Other(String::from(
+            "An input file can only be given for a single day",
+        )));
+    }
+    let variant = variant.unwrap_or(Variant::Both);
+    let ledger = Ledger::load(answers)?;
+    if let ([solver], Variant::First | Variant::Second, None) = (solvers.as_slice(), variant, time)
+    {
+        let file = input.unwrap_or_else(|| default_input(solver.day));
+        let solutions = (solver.solve)(Args::from_raw(variant, file))?;
+        println!("{solutions}");
+        for (variant, solution) in solutions.iter() {
+            eprintln!(
+                "> {variant}: {}",
+                ledger.check(solver.day, variant, solution)
+            );
+        }
+        return Ok(());
+    }
+    let mut failed = 0;
+    for solver in &solvers {
+        let file = input.clone().unwrap_or_else(|| default_input(solver.day));
+        let args = Args::from_raw(variant, file);
+        let result = catch_unwind(|| match time {
+            Some(runs) => {
+                (solver.time)(args, runs).map(|(solutions, timings)| (solutions, Some(timings)))
+            }
+            None => (solver.solve)(args).map(|solutions| (solutions, None)),
+        });
+        match result {
+            Ok(Ok((solutions, timings))) => {
+                solutions.iter().for_each(|(variant, solution)| {
+                    let verdict = ledger.check(solver.day, variant, solution);
+                    println!("{:02} {variant:<6} {solution:<16} {verdict}", solver.day)
+                });
+                if let Some(timings) = timings {
+                    println!("{timings}");
+                }
+            }
+            Ok(Err(why)) => {
+                failed += 1;
+                eprintln!("{:02} {variant:<6} failed: {why}", solver.day)
+            }
+            Err(_) => {
+                failed += 1;
+                eprintln!("{:02} {variant:<6} panicked", solver.day)
+            }
+        }
+    }
+    match failed {
+        0 => Ok(()),
+        _ => Err(Error::Other(format!(
+            "{failed} of {} days failed",
+            solvers.len()
+        ))),
+    }
+}
+
+fn default_input(day: u8) -> String {
+    format!("inputs/inputs-{day:02}")
+}
+
+impl FromStr for Days {
+    type Err = Error;
+
+    fn from_str(raw: &str) -> Result<Self> {
+        if raw == "all" {
+            return Ok(Days(1..=25));
+        }
+        match raw.split_once('-') {
+            Some((first, last)) => Ok(Days(first.parse()?..=last.parse()?)),
+            None => {
+                let day = raw.parse()?;
+                Ok(Days(day..=day))
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Days {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.0.start(), self.0.end()) {
+            (first, last) if first == last => write!(f, "day {first}"),
+            (first, last) => write!(f, "days {first} to {last}"),
+        }
+    }
+}
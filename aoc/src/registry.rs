@@ -0,0 +1,56 @@
+use aoc_utils::{Args, Result, Solutions, Timings};
+
+type Timed = (Solutions<String>, Timings);
+
+/// A type-erased entry point into a day's [`aoc_utils::Problem`] implementation.
+pub struct Solver {
+    pub day: u8,
+    pub name: &'static str,
+    pub solve: fn(Args) -> Result<Solutions<String>>,
+    pub time: fn(Args, usize) -> Result<Timed>,
+}
+
+macro_rules! registry {
+    ($($day:literal => $problem:ty),* $(,)?) => {
+        pub static SOLVERS: &[Solver] = &[$(
+            Solver {
+                day: $day,
+                name: stringify!($problem),
+                solve: |args| {
+                    use aoc_utils::Problem;
+                    <$problem>::solve(args)
+                        .map(|solutions| solutions.map(|solution| solution.to_string()))
+                },
+                time: |args, runs| {
+                    use aoc_utils::Problem;
+                    let (solutions, timings) = <$problem>::time(args, runs)?;
+                    Ok((solutions.map(|solution| solution.to_string()), timings))
+                },
+            },
+        )*];
+    };
+}
+
+registry! {
+    1 => day_01::Day01,
+    2 => day_02::Day02,
+    3 => day_03::Day03,
+    4 => day_04::Day04,
+    5 => day_05::Day05,
+    6 => day_06::Day06,
+    7 => day_07::Day07,
+    8 => day_08::Day08,
+    9 => day_09::Day09,
+    10 => day_10::Day10,
+    11 => day_11::Day11,
+    12 => day_12::Day12,
+    13 => day_13::Day13,
+    14 => day_14::Day14,
+    15 => day_15::Day15,
+    16 => day_16::Day16,
+    17 => day_17::Day17,
+    18 => day_18::Day18,
+    19 => day_19::Day19,
+    20 => day_20::Day20,
+    23 => day_23::Day23,
+}
@@ -0,0 +1,93 @@
+use aoc_utils::{parse, tests, Error, Lines, Result};
+use itertools::Itertools;
+
+const TOTAL_DICE: Dice = Dice {
+    red: 12,
+    green: 13,
+    blue: 14,
+};
+
+#[derive(Debug, Default)]
+struct Dice {
+    red: u8,
+    green: u8,
+    blue: u8,
+}
+
+impl Dice {
+    fn power(self) -> usize {
+        let Dice { red, green, blue } = self;
+        red as usize * green as usize * blue as usize
+    }
+}
+
+#[derive(Debug)]
+struct Bag {
+    id: usize,
+    sets: Vec<Dice>,
+}
+
+pub struct Day02;
+
+impl aoc_utils::Problem<Lines> for Day02 {
+    type Solution = usize;
+
+    fn solve_first(input: Lines) -> aoc_utils::Result<Self::Solution> {
+        input
+            .map(Bag::parse)
+            .filter_ok(Bag::is_possible)
+            .map_ok(|bag| bag.id)
+            .try_fold(0_usize, |sum, res| Ok(sum + res?))
+    }
+
+    fn solve_second(input: Lines) -> aoc_utils::Result<Self::Solution> {
+        input
+            .map(Bag::parse)
+            .map_ok(Bag::minimum_possible)
+            .map_ok(Dice::power)
+            .try_fold(0_usize, |sum, res| Ok(sum + res?))
+    }
+}
+
+impl Bag {
+    pub fn parse<S: AsRef<str>>(raw: std::io::Result<S>) -> Result<Self> {
+        let raw = raw?;
+        let raw = raw.as_ref();
+        let (head, body) = parse::key_value(raw, ":")?;
+        let id = parse::number(parse::prefix(head, "Game ")?)?;
+        let sets = body
+            .split(';')
+            .map(|set| {
+                let mut dice = Dice::default();
+                for entry in set.split(',') {
+                    let (number, color) = parse::key_value(entry.trim(), " ")?;
+                    let number = parse::number(number)?;
+                    match color {
+                        "red" => dice.red = number,
+                        "green" => dice.green = number,
+                        "blue" => dice.blue = number,
+                        _ => return Err(Error::input(format!("Unknown color {color:?}"))),
+                    }
+                }
+                Ok(dice)
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { id, sets })
+    }
+    pub fn is_possible(&self) -> bool {
+        self.sets.iter().all(|set| {
+            set.red <= TOTAL_DICE.red
+                && set.green <= TOTAL_DICE.green
+                && set.blue <= TOTAL_DICE.blue
+        })
+    }
+    pub fn minimum_possible(self) -> Dice {
+        self.sets.iter().fold(Dice::default(), |acc, set| Dice {
+            red: acc.red.max(set.red),
+            green: acc.green.max(set.green),
+            blue: acc.blue.max(set.blue),
+        })
+    }
+}
+
+tests!(Day02, "inputs-02-test" => 8, "inputs-02-test" => 2286);
@@ -0,0 +1,79 @@
+use std::collections::BTreeMap;
+
+use aoc_utils::{parse, Lines, NumberedLine, Result};
+use bit_set::BitSet;
+
+pub struct Day04;
+
+fn calculate_line_score(line: NumberedLine) -> Result<usize> {
+    let (_, relevant) = parse::key_value(&line, ":").map_err(|why| line.locate(why))?;
+    let (first, second) = relevant
+        .split_once('|')
+        .ok_or_else(|| line.error_in(relevant, "Missing '|' delimiter"))?;
+    let winning: BitSet = line.numbers::<usize>(first)?.into_iter().collect();
+    let numbers = line
+        .numbers(second)?
+        .into_iter()
+        .filter(|num| winning.contains(*num))
+        .count();
+    match numbers {
+        0 => Ok(0),
+        x => Ok(1 << (x - 1)),
+    }
+}
+
+fn push_card_line_to_tree(
+    mut tree: BTreeMap<usize, usize>,
+    line: NumberedLine,
+) -> Result<BTreeMap<usize, usize>> {
+    let (name, relevant) = parse::key_value(&line, ":").map_err(|why| line.locate(why))?;
+    let card_id: usize = name
+        .split_ascii_whitespace()
+        .nth(1)
+        .map(|id| line.parse(id))
+        .ok_or_else(|| line.error_in(name, "Missing card id"))??;
+    let (first, second) = relevant
+        .split_once('|')
+        .ok_or_else(|| line.error_in(relevant, "Missing '|' delimiter"))?;
+    let winning: BitSet = line.numbers::<usize>(first)?.into_iter().collect();
+    let numbers = line
+        .numbers(second)?
+        .into_iter()
+        .filter(|num| winning.contains(*num))
+        .count();
+    *tree.entry(card_id).or_insert(0) += 1;
+    match numbers {
+        0 => Ok(tree),
+        x => {
+            let curr_count = tree.get(&card_id).copied().unwrap_or_default();
+            for next in card_id + 1..=card_id + x {
+                *tree.entry(next).or_insert(0) += curr_count;
+            }
+            Ok(tree)
+        }
+    }
+}
+
+impl aoc_utils::Problem<Lines> for Day04 {
+    type Solution = usize;
+
+    fn solve_first(input: Lines) -> aoc_utils::Result<Self::Solution> {
+        input
+            .numbered()
+            .map(|line| calculate_line_score(line?))
+            .sum()
+    }
+
+    fn solve_second(input: Lines) -> aoc_utils::Result<Self::Solution> {
+        let solution = input
+            .numbered()
+            .try_fold(BTreeMap::default(), |tree, line| {
+                push_card_line_to_tree(tree, line?)
+            })?
+            .values()
+            .sum();
+        Ok(solution)
+    }
+}
+
+aoc_utils::tests!(Day04, "inputs-04-test" => 13, "inputs-04-test" => 30);
@@ -0,0 +1,115 @@
+#![feature(iter_array_chunks)]
+use std::ops::Range;
+
+use aoc_utils::{parse, ranges::RangeSet, Error, Lines, NumberedLine, Result};
+
+type Seed = i64;
+
+pub struct Day05;
+
+#[derive(Debug)]
+enum Line {
+    Empty,
+    Header,
+    Map { from: Range<Seed>, offset: Seed },
+}
+
+/// The seeds already moved by the current map, and the ones still waiting for it.
+#[derive(Debug)]
+struct Almanac {
+    mapped: RangeSet,
+    unmapped: RangeSet,
+}
+
+impl aoc_utils::Problem<Lines> for Day05 {
+    type Solution = usize;
+
+    fn solve_first(input: Lines) -> aoc_utils::Result<Self::Solution> {
+        solve_helper(input, parse_seed_number_line)
+    }
+
+    fn solve_second(input: Lines) -> aoc_utils::Result<Self::Solution> {
+        solve_helper(input, parse_seed_range_line)
+    }
+}
+
+fn solve_helper<F>(input: Lines, seed_aggregator: F) -> Result<usize>
+where
+    F: FnOnce(NumberedLine) -> Result<RangeSet>,
+{
+    let mut input = input.numbered();
+    let seeds = seed_aggregator(
+        input
+            .next()
+            .ok_or_else(|| Error::input("Not enough lines"))??,
+    )?;
+    let almanac = Almanac {
+        mapped: RangeSet::new(),
+        unmapped: seeds,
+    };
+    let almanac = input
+        .map(|line| Line::parse(line?))
+        .try_fold(almanac, apply_line)?;
+    let lowest = almanac
+        .mapped
+        .union(&almanac.unmapped)
+        .min()
+        .ok_or_else(|| Error::input("No seeds?"))?;
+    usize::try_from(lowest).map_err(|_| Error::input(format!("Negative location {lowest}")))
+}
+
+fn apply_line(almanac: Almanac, line: Result<Line>) -> Result<Almanac> {
+    let Almanac { mapped, unmapped } = almanac;
+    match line? {
+        Line::Empty => Ok(Almanac { mapped, unmapped }),
+        Line::Header => Ok(Almanac {
+            mapped: RangeSet::new(),
+            unmapped: mapped.union(&unmapped),
+        }),
+        Line::Map { from, offset } => {
+            let from = RangeSet::from(from);
+            Ok(Almanac {
+                mapped: mapped.union(&unmapped.intersection(&from).shifted(offset)),
+                unmapped: unmapped.difference(&from),
+            })
+        }
+    }
+}
+
+impl Line {
+    fn parse(line: NumberedLine) -> Result<Self> {
+        if line.is_empty() {
+            Ok(Self::Empty)
+        } else if line.bytes().next().map(|byte| byte.is_ascii_digit()) == Some(true) {
+            let [to_start, from_start, len] = line.numbers(&line)?[..] else {
+                return Err(line.error("Expected three numbers on line"));
+            };
+            Ok(Line::Map {
+                from: from_start..from_start + len,
+                offset: to_start - from_start,
+            })
+        } else {
+            Ok(Self::Header)
+        }
+    }
+}
+
+fn parse_seed_range_line(input: NumberedLine) -> Result<RangeSet> {
+    Ok(parse_seed_line_helper(&input)?
+        .array_chunks()
+        .map(|[start, len]| start..start + len)
+        .collect())
+}
+
+fn parse_seed_number_line(input: NumberedLine) -> Result<RangeSet> {
+    Ok(parse_seed_line_helper(&input)?
+        .map(|seed| seed..seed + 1)
+        .collect())
+}
+
+fn parse_seed_line_helper(input: &NumberedLine) -> Result<impl Iterator<Item = Seed>> {
+    let seeds = parse::prefix(input, "seeds:").map_err(|why| input.locate(why))?;
+    Ok(input.numbers(seeds)?.into_iter())
+}
+
+aoc_utils::tests!(Day05, "inputs-05-test" => 35, "inputs-05-test" => 46);
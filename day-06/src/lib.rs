@@ -0,0 +1,58 @@
+use std::num::ParseIntError;
+
+use aoc_utils::{math, Error, Lines, Result};
+
+pub struct Day06;
+
+impl aoc_utils::Problem<Lines> for Day06 {
+    type Solution = usize;
+
+    fn solve_first(mut input: Lines) -> aoc_utils::Result<Self::Solution> {
+        let first = input
+            .next()
+            .ok_or_else(|| Error::input("Where is the first line?"))??;
+        let first = first
+            .split_ascii_whitespace()
+            .skip(1)
+            .map(str::parse::<usize>);
+        let second = input
+            .next()
+            .ok_or_else(|| Error::input("Where is the second line?"))??;
+        let second = second
+            .split_ascii_whitespace()
+            .skip(1)
+            .map(str::parse::<usize>);
+        first.zip(second).try_fold(1_usize, add_factor)
+    }
+
+    fn solve_second(mut input: Lines) -> aoc_utils::Result<Self::Solution> {
+        let first = input
+            .next()
+            .ok_or_else(|| Error::input("Where is the first line?"))??;
+        let second = input
+            .next()
+            .ok_or_else(|| Error::input("Where is the second line?"))??;
+        let total_time = first
+            .bytes()
+            .filter(|byte| byte.is_ascii_digit())
+            .fold(0_usize, |num, digit| num * 10 + (digit - b'0') as usize);
+        let winning_distance = second
+            .bytes()
+            .filter(|byte| byte.is_ascii_digit())
+            .fold(0_usize, |num, digit| num * 10 + (digit - b'0') as usize);
+        add_factor(1, (Ok(total_time), Ok(winning_distance)))
+    }
+}
+
+fn add_factor(
+    product: usize,
+    (total_time, winning_distance): (Result<usize, ParseIntError>, Result<usize, ParseIntError>),
+) -> Result<usize> {
+    let (total_time, winning_distance) = (total_time? as i64, winning_distance? as i64);
+    // Holding for `hold` wins if hold * (total_time - hold) > winning_distance
+    let holds = math::quadratic_below_zero(1, -total_time, winning_distance);
+    let ways_to_win = holds.end() - holds.start() + 1;
+    Ok(product * ways_to_win.max(0) as usize)
+}
+
+aoc_utils::tests!(Day06, "inputs-06-test" => 288, "inputs-06-test" => 71503);
@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use aoc_utils::{
+    tracing::{debug, enabled, trace, Level},
+    Direction, Grid, Idx2D,
+};
+
+pub struct Day10;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Pipe {
+    NE,
+    NS,
+    NW,
+    EW,
+    SE,
+    SW,
+    Blank,
+    Start,
+}
+
+/// Which side of the loop a cell is on, seen in the direction the loop is walked.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Side {
+    Left,
+    Right,
+}
+
+impl aoc_utils::Problem<Grid<Pipe>> for Day10 {
+    type Solution = usize;
+
+    fn solve_first(grid: Grid<Pipe>) -> aoc_utils::Result<Self::Solution> {
+        let start = grid
+            .find_idx(|&pipe| pipe == Pipe::Start)
+            .expect("a starting position");
+        trace!("pipes:{grid}");
+        for dir in Direction::iter() {
+            let just_count = |num, _, _| num + 1;
+            let circle_len = match fold_over_circle(&grid, start, dir, 0_usize, just_count) {
+                Some(circle_len) => circle_len,
+                None => continue,
+            };
+            if enabled!(Level::DEBUG) {
+                use std::collections::HashSet;
+                let assemble_circle = |mut list: HashSet<_>, pos, _| {
+                    list.insert(pos);
+                    list
+                };
+                let circle =
+                    fold_over_circle(&grid, start, dir, HashSet::new(), assemble_circle).unwrap();
+                let rendered = render_loop(&grid, |pos| circle.contains(&pos), |_| false);
+                debug!(circle_len, "loop:\n{rendered}");
+            }
+            return Ok(circle_len / 2);
+        }
+        unreachable!()
+    }
+
+    fn solve_second(grid: Grid<Pipe>) -> aoc_utils::Result<Self::Solution> {
+        use Direction::*;
+        use Side::*;
+
+        let start = grid
+            .find_idx(|&pipe| pipe == Pipe::Start)
+            .expect("a starting position");
+        trace!("pipes:{grid}");
+        for dir in Direction::iter() {
+            let accumulate_circle_map = |mut map: HashMap<_, _>, pos, dir| {
+                map.insert(pos, dir);
+                map
+            };
+            let mut circle =
+                match fold_over_circle(&grid, start, dir, HashMap::new(), accumulate_circle_map) {
+                    Some(circle) => circle,
+                    None => continue,
+                };
+            // The fold stops on the start without visiting it, but it is on the loop too
+            circle.insert(start, dir);
+            let components = grid.components(|pos, _| !circle.contains_key(&pos));
+            let mut sides = vec![None; components.regions.len()];
+            for ((x, y), dir) in &circle {
+                let symbol = grid[(*x, *y)];
+                let instructions = match (symbol, dir) {
+                    (Pipe::NE, North) => [(West, Right), (North, Right)].as_slice(),
+                    (Pipe::NE, East) => &[(South, Left), (West, Left)],
+                    (Pipe::NS, North) => &[(West, Right), (East, Left)],
+                    (Pipe::NS, South) => &[(West, Left), (East, Right)],
+                    (Pipe::NW, North) => &[(East, Left), (South, Left)],
+                    (Pipe::NW, West) => &[(South, Right), (East, Right)],
+                    (Pipe::EW, East) => &[(South, Left), (North, Right)],
+                    (Pipe::EW, West) => &[(North, Left), (South, Right)],
+                    (Pipe::SE, East) => &[(North, Right), (West, Right)],
+                    (Pipe::SE, South) => &[(North, Left), (West, Left)],
+                    (Pipe::SW, South) => &[(East, Right), (North, Right)],
+                    (Pipe::SW, West) => &[(East, Left), (North, Left)],
+                    _ => &[],
+                };
+                for (dir, side) in instructions {
+                    if let Some(label) = grid
+                        .walk((*x, *y), *dir)
+                        .and_then(|pos| components.label(pos))
+                    {
+                        sides[label] = Some(*side);
+                    }
+                }
+            }
+            // Regions touching the border are outside, so their side tells which one is
+            let outside = components
+                .regions
+                .iter()
+                .zip(&sides)
+                .find(|(region, side)| region.touches_border && side.is_some())
+                .and_then(|(_, side)| *side);
+            let Some(outside) = outside else {
+                return Err(aoc_utils::Error::Other(String::from(
+                    "Could not decide which side of the loop is outside",
+                )));
+            };
+            let is_inside = |label: usize| sides[label].is_some_and(|side| side != outside);
+            debug!(
+                ?outside,
+                "enclosed tiles:\n{}",
+                render_loop(
+                    &grid,
+                    |pos| circle.contains_key(&pos),
+                    |pos| components.label(pos).is_some_and(is_inside)
+                )
+            );
+            let enclosed = components
+                .regions
+                .iter()
+                .enumerate()
+                .filter(|(label, _)| is_inside(*label))
+                .map(|(_, region)| region.size())
+                .sum();
+            return Ok(enclosed);
+        }
+        unreachable!()
+    }
+}
+
+fn fold_over_circle<T, F>(
+    grid: &Grid<Pipe>,
+    start: (usize, usize),
+    dir: Direction,
+    init: T,
+    acc: F,
+) -> Option<T>
+where
+    F: Fn(T, (usize, usize), Direction) -> T,
+{
+    let mut last_dir = dir;
+    let mut circle = init;
+    let mut curr = grid.walk(start, dir)?;
+    circle = acc(circle, curr, dir);
+    while curr != start {
+        let dir = grid[curr].steer(last_dir.invert())?;
+        circle = acc(circle, curr, dir);
+        last_dir = dir;
+        curr = grid.walk(curr, dir)?;
+    }
+    Some(circle)
+}
+
+impl Pipe {
+    fn steer(&self, old_dir: Direction) -> Option<Direction> {
+        use Direction::*;
+        match (self, old_dir) {
+            (Pipe::NE, North) => Some(East),
+            (Pipe::NE, East) => Some(North),
+            (Pipe::NS, North) => Some(South),
+            (Pipe::NS, South) => Some(North),
+            (Pipe::NW, North) => Some(West),
+            (Pipe::NW, West) => Some(North),
+            (Pipe::EW, East) => Some(West),
+            (Pipe::EW, West) => Some(East),
+            (Pipe::SE, East) => Some(South),
+            (Pipe::SE, South) => Some(East),
+            (Pipe::SW, South) => Some(West),
+            (Pipe::SW, West) => Some(South),
+            _ => None,
+        }
+    }
+}
+
+impl From<u8> for Pipe {
+    fn from(value: u8) -> Self {
+        match value {
+            b'|' => Pipe::NS,
+            b'-' => Pipe::EW,
+            b'L' => Pipe::NE,
+            b'J' => Pipe::NW,
+            b'7' => Pipe::SW,
+            b'F' => Pipe::SE,
+            b'.' => Pipe::Blank,
+            b'S' => Pipe::Start,
+            _ => unreachable!("{:?} is not valid here", char::from_u32(value as u32)),
+        }
+    }
+}
+
+/// Draw the loop, the tiles it encloses as `*` and everything else as `.`.
+fn render_loop<L, E>(grid: &Grid<Pipe>, on_loop: L, enclosed: E) -> String
+where
+    L: Fn(Idx2D) -> bool,
+    E: Fn(Idx2D) -> bool,
+{
+    let (on_loop, enclosed) = (&on_loop, &enclosed);
+    let symbol = |pos, cell: &Pipe| {
+        if *cell == Pipe::Start || on_loop(pos) {
+            cell.to_string()
+        } else if enclosed(pos) {
+            String::from("*")
+        } else {
+            String::from(".")
+        }
+    };
+    #[cfg(feature = "viz")]
+    {
+        use aoc_utils::viz::{Color, Style};
+        let start = Style::new(Color::Red).bold();
+        let inside = Style::new(Color::Green).bold();
+        grid.styled()
+            .style_with(move |pos, cell, style| {
+                if *cell == Pipe::Start {
+                    start
+                } else if on_loop(pos) {
+                    style.bold()
+                } else if enclosed(pos) {
+                    inside
+                } else {
+                    style.dimmed()
+                }
+            })
+            .legend('S', start, "start")
+            .legend('*', inside, "enclosed")
+            .render(symbol)
+    }
+    #[cfg(not(feature = "viz"))]
+    grid.render(symbol)
+}
+
+impl std::fmt::Display for Pipe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Pipe::NE => '╰',
+                Pipe::NS => '│',
+                Pipe::NW => '╯',
+                Pipe::EW => '─',
+                Pipe::SE => '╭',
+                Pipe::SW => '╮',
+                Pipe::Blank => '.',
+                Pipe::Start => 'S',
+            }
+        )
+    }
+}
+
+aoc_utils::tests! {
+    Day10,
+    first {
+        square_loop: "inputs-10-test-first-1" => 4,
+        complex_loop: "inputs-10-test-first-2" => 8,
+    },
+    second {
+        enclosed: "inputs-10-test-second" => 10,
+        straight_start: "inputs-10-test-second-2" => 3,
+    },
+}
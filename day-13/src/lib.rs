@@ -0,0 +1,90 @@
+use aoc_utils::{Direction, Error, Grid, GridView, Lines, Result};
+
+pub struct Day13;
+
+/// Axes as the number of rows above and columns left of them.
+type Axes = (Option<usize>, Option<usize>);
+
+impl aoc_utils::Problem<Lines> for Day13 {
+    type Solution = usize;
+
+    fn solve_first(input: Lines) -> aoc_utils::Result<Self::Solution> {
+        let sum = parse_blocks(input)?
+            .iter()
+            .map(|block| score(find_axes(block, (None, None))))
+            .sum();
+        Ok(sum)
+    }
+
+    fn solve_second(input: Lines) -> aoc_utils::Result<Self::Solution> {
+        let mut sum = 0;
+        for mut block in parse_blocks(input)? {
+            let original = find_axes(&block, (None, None));
+            let positions: Vec<_> = block.iter_pos().map(|(pos, _)| pos).collect();
+            sum += positions
+                .into_iter()
+                .find_map(|smudge_pos| {
+                    smudge(&mut block[smudge_pos]);
+                    let axes = find_axes(&block, original);
+                    // Reset block
+                    smudge(&mut block[smudge_pos]);
+                    match axes {
+                        (None, None) => None,
+                        axes => Some(score(axes)),
+                    }
+                })
+                .ok_or_else(|| Error::input("No smudge leads to a new reflection"))?;
+        }
+        Ok(sum)
+    }
+}
+
+fn parse_blocks(input: Lines) -> Result<Vec<Grid<u8>>> {
+    let mut blocks = vec![];
+    let mut curr_block = vec![];
+    for line in input.chain(vec![Ok(String::new())]) {
+        let line = line?;
+        if line.is_empty() {
+            if !curr_block.is_empty() {
+                blocks.push(Grid::from_rows(
+                    curr_block.drain(..).map(String::into_bytes),
+                )?);
+            }
+        } else {
+            curr_block.push(line);
+        }
+    }
+    Ok(blocks)
+}
+
+fn smudge(cell: &mut u8) {
+    *cell = match cell {
+        b'#' => b'.',
+        _ => b'#',
+    };
+}
+
+fn score((rows, cols): Axes) -> usize {
+    rows.unwrap_or_default() * 100 + cols.unwrap_or_default()
+}
+
+/// Find the horizontal and vertical axis, except the ones to `ignore`.
+fn find_axes(block: &Grid<u8>, (ignore_rows, ignore_cols): Axes) -> Axes {
+    // Looking from the west, the columns become rows
+    (
+        find_reflection_axis(block.view(Direction::North), ignore_rows),
+        find_reflection_axis(block.view(Direction::West), ignore_cols),
+    )
+}
+
+/// Find a horizontal reflection axis, as the number of rows above it.
+fn find_reflection_axis(block: GridView<u8>, ignore: Option<usize>) -> Option<usize> {
+    (1..block.height())
+        .filter(|axis| Some(*axis) != ignore)
+        .find(|&axis| {
+            (0..axis.min(block.height() - axis))
+                .all(|offset| block.row(axis - 1 - offset).eq(block.row(axis + offset)))
+        })
+}
+
+aoc_utils::tests!(Day13, "inputs-13-test" => 405, "inputs-13-test" => 400);
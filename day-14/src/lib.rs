@@ -0,0 +1,90 @@
+use aoc_utils::{Direction, Grid, GridViewMut};
+
+pub struct Day14;
+
+impl aoc_utils::Problem<Grid<u8>> for Day14 {
+    type Solution = usize;
+
+    fn solve_first(mut grid: Grid<u8>) -> aoc_utils::Result<Self::Solution> {
+        tilt(grid.view_mut(Direction::North));
+        Ok(calculate_score(&grid))
+    }
+
+    fn solve_second(grid: Grid<u8>) -> aoc_utils::Result<Self::Solution> {
+        let mut record = recorder()?;
+        let grid = aoc_utils::cycle::state_after(grid, 1_000_000_000, |grid| {
+            let mut grid = grid.clone();
+            spin_cycle(&mut grid, &mut record);
+            grid
+        });
+        Ok(calculate_score(&grid))
+    }
+}
+
+fn calculate_score(grid: &Grid<u8>) -> usize {
+    grid.iter_pos()
+        .filter(|(_, cell)| **cell == b'O')
+        .map(|((_, y), _)| grid.height() - y)
+        .sum()
+}
+
+/// Tilt north, west, south and east, showing `record` the platform after each.
+fn spin_cycle<R: FnMut(&Grid<u8>)>(grid: &mut Grid<u8>, record: &mut R) {
+    for up in [
+        Direction::North,
+        Direction::West,
+        Direction::South,
+        Direction::East,
+    ] {
+        tilt(grid.view_mut(up));
+        record(grid);
+    }
+}
+
+/// Save every platform shown into the directory in `AOC_FRAMES`, with the `viz` feature.
+#[cfg(feature = "viz")]
+fn recorder() -> aoc_utils::Result<impl FnMut(&Grid<u8>)> {
+    use aoc_utils::{tracing::warn, Frames, Rgb};
+    let mut frames = Frames::from_env(4)?;
+    Ok(move |grid: &Grid<u8>| {
+        let Some(recording) = &mut frames else {
+            return;
+        };
+        let color = |_, cell: &u8| match cell {
+            b'O' => Rgb(230, 180, 60),
+            b'#' => Rgb(90, 90, 100),
+            _ => Rgb(20, 20, 30),
+        };
+        if let Err(why) = recording.push(grid, color) {
+            warn!("Stopped saving frames: {why}");
+            frames = None;
+        }
+    })
+}
+
+#[cfg(not(feature = "viz"))]
+fn recorder() -> aoc_utils::Result<impl FnMut(&Grid<u8>)> {
+    Ok(|_: &Grid<u8>| {})
+}
+
+/// Roll every round rock up as far as it goes.
+fn tilt(mut view: GridViewMut<u8>) {
+    let mut next_free_pos = vec![0; view.width()];
+    for y in 0..view.height() {
+        for x in 0..view.width() {
+            match view[(x, y)] {
+                b'O' => {
+                    view[(x, y)] = b'.';
+                    view[(x, next_free_pos[x])] = b'O';
+                    next_free_pos[x] += 1;
+                }
+                b'#' => {
+                    next_free_pos[x] = y + 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+aoc_utils::tests!(Day14, "inputs-14-test" => 136, "inputs-14-test" => 64);
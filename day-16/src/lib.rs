@@ -0,0 +1,158 @@
+use aoc_utils::{Direction, Grid};
+
+pub struct Day16;
+
+#[derive(Debug)]
+pub enum Tile {
+    EmptySpace,
+    MirrorNE,
+    MirrorNW,
+    SplitterHorizontal,
+    SplitterVertical,
+}
+
+impl aoc_utils::Problem<Grid<Tile>> for Day16 {
+    type Solution = usize;
+
+    fn solve_first(input: Grid<Tile>) -> aoc_utils::Result<Self::Solution> {
+        let count = fill_floor((0, 0), Direction::East, &input, recorder(&input)?);
+        Ok(count)
+    }
+
+    fn solve_second(input: Grid<Tile>) -> aoc_utils::Result<Self::Solution> {
+        let vert = (0..input.height()).flat_map(|y| {
+            [
+                ((0, y), Direction::East),
+                ((input.width() - 1, y), Direction::West),
+            ]
+        });
+        let horiz = (0..input.width()).flat_map(|x| {
+            [
+                ((x, 0), Direction::South),
+                ((x, input.height() - 1), Direction::North),
+            ]
+        });
+        let mut curr_max = 0;
+        for (init, dir) in vert.chain(horiz) {
+            let count = fill_floor(init, dir, &input, |_| {});
+            curr_max = curr_max.max(count);
+        }
+        Ok(curr_max)
+    }
+}
+
+/// Count the tiles a beam energizes, showing `record` the beams whenever it reaches a new one.
+fn fill_floor<R>(pos: (usize, usize), dir: Direction, grid: &Grid<Tile>, mut record: R) -> usize
+where
+    R: FnMut(&Grid<u8>),
+{
+    use Direction as D;
+    use Tile as T;
+    let mut lava = grid.clone_with(0_u8);
+    let mut queue = vec![(pos, dir)];
+    let mut count = 0;
+    macro_rules! push_if_walk {
+        ($pos:expr, $dir:expr) => {
+            match grid.walk($pos, $dir) {
+                Some(new_pos) => queue.push((new_pos, $dir)),
+                None => {}
+            }
+        };
+    }
+    while let Some((pos, dir)) = queue.pop() {
+        let tile = &grid[pos];
+        if lava[pos] & dir.bit() != 0 {
+            continue;
+        }
+        if lava[pos] == 0 {
+            count += 1;
+        }
+        lava[pos] |= dir.bit();
+        if lava[pos] == dir.bit() {
+            record(&lava);
+        }
+        match (tile, dir) {
+            (T::MirrorNE, D::North) => push_if_walk!(pos, D::West),
+            (T::MirrorNE, D::East) => push_if_walk!(pos, D::South),
+            (T::MirrorNE, D::South) => push_if_walk!(pos, D::East),
+            (T::MirrorNE, D::West) => push_if_walk!(pos, D::North),
+            (T::MirrorNW, D::North) => push_if_walk!(pos, D::East),
+            (T::MirrorNW, D::East) => push_if_walk!(pos, D::North),
+            (T::MirrorNW, D::South) => push_if_walk!(pos, D::West),
+            (T::MirrorNW, D::West) => push_if_walk!(pos, D::South),
+            (T::SplitterHorizontal, D::North | D::South) => {
+                push_if_walk!(pos, D::East);
+                push_if_walk!(pos, D::West);
+            }
+            (T::SplitterVertical, D::East | D::West) => {
+                push_if_walk!(pos, D::North);
+                push_if_walk!(pos, D::South);
+            }
+            (T::EmptySpace, _)
+            | (T::SplitterHorizontal, D::East | D::West)
+            | (T::SplitterVertical, D::North | D::South) => push_if_walk!(pos, dir),
+        }
+    }
+
+    count
+}
+
+/// Save the floor with every set of beams shown into the directory in
+/// `AOC_FRAMES`, with the `viz` feature.
+#[cfg(feature = "viz")]
+fn recorder(grid: &Grid<Tile>) -> aoc_utils::Result<impl FnMut(&Grid<u8>) + '_> {
+    use aoc_utils::{tracing::warn, Frames, Rgb};
+    let mut frames = Frames::from_env(4)?;
+    Ok(move |lava: &Grid<u8>| {
+        let Some(recording) = &mut frames else {
+            return;
+        };
+        let color = |pos, beams: &u8| match (beams, &grid[pos]) {
+            (0, Tile::EmptySpace) => Rgb(20, 20, 30),
+            (0, _) => Rgb(90, 90, 100),
+            (_, Tile::EmptySpace) => Rgb(240, 120, 30),
+            (_, _) => Rgb(255, 220, 120),
+        };
+        if let Err(why) = recording.push(lava, color) {
+            warn!("Stopped saving frames: {why}");
+            frames = None;
+        }
+    })
+}
+
+#[cfg(not(feature = "viz"))]
+fn recorder(_: &Grid<Tile>) -> aoc_utils::Result<impl FnMut(&Grid<u8>)> {
+    Ok(|_: &Grid<u8>| {})
+}
+
+impl From<u8> for Tile {
+    fn from(value: u8) -> Self {
+        use Tile::*;
+        match value {
+            b'.' => EmptySpace,
+            b'\\' => MirrorNE,
+            b'/' => MirrorNW,
+            b'-' => SplitterHorizontal,
+            b'|' => SplitterVertical,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl std::fmt::Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Tile::EmptySpace => '.',
+                Tile::MirrorNE => '\\',
+                Tile::MirrorNW => '/',
+                Tile::SplitterHorizontal => '-',
+                Tile::SplitterVertical => '|',
+            }
+        )
+    }
+}
+
+aoc_utils::tests!(Day16, "inputs-16-test" => 46, "inputs-16-test" => 51);
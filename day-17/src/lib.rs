@@ -0,0 +1,103 @@
+use std::num::NonZeroU8;
+
+use aoc_utils::{
+    search::{self, Found},
+    tracing::{debug, trace},
+    Direction, Grid, Idx2D,
+};
+
+pub struct Day17;
+
+pub struct Number(NonZeroU8);
+
+/// Where a crucible is and the direction it last moved in, if any.
+type Crucible = (Idx2D, Option<Direction>);
+
+impl aoc_utils::Problem<Grid<Number>> for Day17 {
+    type Solution = usize;
+
+    fn solve_first(input: Grid<Number>) -> aoc_utils::Result<Self::Solution> {
+        least_heat_loss(&input, 1, 3)
+    }
+
+    fn solve_second(input: Grid<Number>) -> aoc_utils::Result<Self::Solution> {
+        least_heat_loss(&input, 4, 10)
+    }
+}
+
+/// Move a crucible from the top left to the bottom right, turning after at
+/// least `min` and at most `max` blocks in a straight line.
+fn least_heat_loss(grid: &Grid<Number>, min: usize, max: usize) -> aoc_utils::Result<usize> {
+    let goal = (grid.width() - 1, grid.height() - 1);
+    let successors = |&(pos, last_dir): &Crucible| {
+        trace!(?pos, ?last_dir, "expanding");
+        let mut next = vec![];
+        for dir in Direction::iter() {
+            if last_dir.is_some_and(|last_dir| dir == last_dir || dir == last_dir.invert()) {
+                continue;
+            }
+            let (mut curr, mut loss) = (pos, 0);
+            for steps in 1..=max {
+                let Some(pos) = grid.walk(curr, dir) else {
+                    break;
+                };
+                curr = pos;
+                loss += grid[curr].get() as usize;
+                if steps >= min {
+                    next.push(((curr, Some(dir)), loss));
+                }
+            }
+        }
+        next
+    };
+    let heuristic = |&(pos, _): &Crucible| search::manhattan(pos, goal);
+    let Found { cost, path } =
+        search::a_star(((0, 0), None), successors, heuristic, |&(pos, _)| {
+            pos == goal
+        })
+        .ok_or_else(|| aoc_utils::Error::input("The crucible can not reach the factory"))?;
+    let corners: Vec<_> = path.into_iter().map(|(pos, _)| pos).collect();
+    debug!(cost, "path:\n{}", render_path(grid, &corners));
+    Ok(cost)
+}
+
+/// Draw the heat loss of every block and where the crucible turns.
+fn render_path(grid: &Grid<Number>, corners: &[Idx2D]) -> String {
+    #[cfg(feature = "viz")]
+    {
+        use aoc_utils::viz::{colorous, Color, Style};
+        let turn = Color::RGB(150, 0, 0);
+        grid.styled()
+            .color_map(colorous::BLUES, |_, val| f64::from(val.get()) / 9.0)
+            .highlight(corners.iter().copied(), move |style| style.bg(turn))
+            .legend("1-9", Style::default(), "heat loss, darker is more")
+            .legend(' ', Style::default().bg(turn), "turn")
+            .render(|_, val| val.get())
+    }
+    #[cfg(not(feature = "viz"))]
+    grid.render(|pos, val| match corners.contains(&pos) {
+        true => '#',
+        false => char::from(b'0' + val.get()),
+    })
+}
+
+impl TryFrom<u8> for Number {
+    type Error = aoc_utils::Error;
+
+    fn try_from(raw: u8) -> Result<Self, Self::Error> {
+        match raw {
+            b'1'..=b'9' => Ok(Number(NonZeroU8::new(raw - b'0').unwrap())),
+            _ => Err(aoc_utils::Error::input("Invalid number in grid")),
+        }
+    }
+}
+
+impl std::ops::Deref for Number {
+    type Target = NonZeroU8;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+aoc_utils::tests!(Day17, "inputs-17-test" => 102, "inputs-17-test" => 94);
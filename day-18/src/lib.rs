@@ -0,0 +1,99 @@
+use aoc_utils::{
+    math,
+    tracing::{debug, enabled, Level},
+    Direction, Idx2D, InputLine, Lines,
+};
+
+pub struct Day18;
+
+#[derive(Debug)]
+pub struct Line {
+    dir1: Direction,
+    steps1: usize,
+    dir2: Direction,
+    steps2: usize,
+}
+
+impl aoc_utils::Problem<Lines<Line>> for Day18 {
+    type Solution = usize;
+
+    fn solve_first(input: Lines<Line>) -> aoc_utils::Result<Self::Solution> {
+        let steps: Vec<_> = input.map(|line| (line.dir1, line.steps1)).collect();
+        if enabled!(Level::DEBUG) {
+            let mut trench = aoc_utils::SparseGrid::new();
+            let mut pos = (0, 0);
+            for &(dir, steps) in &steps {
+                for _ in 0..steps {
+                    trench.insert(pos, dir);
+                    pos = trench.walk(pos, dir);
+                }
+            }
+            let rendered = trench.render(|_, dir| dir.map_or('.', |dir| dir.arrow()));
+            debug!("trench:\n{rendered}");
+        }
+        Ok(lagoon_size(corners(steps)))
+    }
+
+    fn solve_second(input: Lines<Line>) -> aoc_utils::Result<Self::Solution> {
+        let steps = input.map(|line| (line.dir2, line.steps2));
+        Ok(lagoon_size(corners(steps)))
+    }
+}
+
+/// The corners of the trench dug by following `steps`, starting and ending at the origin.
+fn corners(
+    steps: impl IntoIterator<Item = (Direction, usize)>,
+) -> impl Iterator<Item = Idx2D<i64>> {
+    steps.into_iter().chain([(Direction::North, 0)]).scan(
+        (0, 0),
+        |(curr_x, curr_y), (dir, steps)| {
+            let pos = (*curr_x, *curr_y);
+            let (x_off, y_off) = Idx2D::<isize>::from(dir);
+            *curr_x += x_off as i64 * steps as i64;
+            *curr_y += y_off as i64 * steps as i64;
+            Some(pos)
+        },
+    )
+}
+
+/// The number of cubic meters dug out for a trench with the given corners,
+/// counting both the trench and its inside.
+fn lagoon_size(corners: impl Iterator<Item = Idx2D<i64>>) -> usize {
+    let corners: Vec<_> = corners.collect();
+    let boundary = math::boundary_points(corners.iter().copied());
+    let interior = math::interior_points(math::double_area(corners), boundary);
+    (interior + boundary) as usize
+}
+
+impl From<InputLine> for Line {
+    fn from(line: InputLine) -> Self {
+        let line = line.unwrap();
+        let mut line = line.split_ascii_whitespace();
+        let dir1 = match line.next().unwrap() {
+            "U" => Direction::North,
+            "D" => Direction::South,
+            "L" => Direction::West,
+            "R" => Direction::East,
+            _ => unreachable!(),
+        };
+        let steps1 = line.next().unwrap().parse().unwrap();
+        let second = &line.next().unwrap();
+        let second = &second[2..second.len() - 1];
+        let steps2 = usize::from_str_radix(&second[0..5], 16).unwrap();
+        let dir2 = match &second[5..6] {
+            "0" => Direction::East,
+            "1" => Direction::South,
+            "2" => Direction::West,
+            "3" => Direction::North,
+            x => unreachable!("{x:?}"),
+        };
+        Line {
+            dir1,
+            steps1,
+            dir2,
+            steps2,
+        }
+    }
+}
+
+aoc_utils::tests!(Day18, "inputs-18-test" => 62, "inputs-18-test" => 952408144115);
@@ -0,0 +1,128 @@
+use aoc_utils::{
+    memo::Memo,
+    tracing::{debug, trace},
+    Direction, Grid, JunctionGraph,
+};
+
+pub struct Day23;
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum Plot {
+    Path,
+    Forest,
+    Slope(Direction),
+}
+
+/// The trails between the start, the end and every fork.
+struct Graph {
+    junctions: JunctionGraph,
+}
+
+impl aoc_utils::Problem<Grid<Plot>> for Day23 {
+    type Solution = usize;
+
+    fn solve_first(input: Grid<Plot>) -> aoc_utils::Result<Self::Solution> {
+        Graph::from(&input, true).longest_path_len()
+    }
+
+    fn solve_second(input: Grid<Plot>) -> aoc_utils::Result<Self::Solution> {
+        Graph::from(&input, false).longest_path_len()
+    }
+}
+
+impl Graph {
+    /// Node `0` is the start in the top row and node `1` the end in the bottom row.
+    fn from(grid: &Grid<Plot>, directed: bool) -> Self {
+        let open = |row| {
+            grid.row(row)
+                .find(|(_, plot)| **plot != Plot::Forest)
+                .map(|(pos, _)| pos)
+        };
+        let points: Vec<_> = open(0)
+            .into_iter()
+            .chain(open(grid.height().saturating_sub(1)))
+            .collect();
+        let junctions = grid.junction_graph(
+            &points,
+            |_, plot| *plot != Plot::Forest,
+            |_, plot| match plot {
+                Plot::Slope(dir) if directed => Some(*dir),
+                _ => None,
+            },
+        );
+        debug!(nodes = junctions.nodes.len(), "compressed the trails");
+        trace!("junctions:\n{}", junctions.to_dot());
+        Graph { junctions }
+    }
+
+    fn longest_path_len(&self) -> aoc_utils::Result<usize> {
+        if self.junctions.nodes.len() < 2 {
+            return Err(aoc_utils::Error::input(
+                "The map needs an open start in the top row and end in the bottom row",
+            ));
+        }
+        if self.junctions.nodes.len() > u64::BITS as usize {
+            return Err(aoc_utils::Error::input(
+                "Too many forks to track the visited ones",
+            ));
+        }
+        let mut memo = Memo::new();
+        let len = _longest_path_len(self, 0, 1, 0, &mut memo);
+        debug!("longest path memo: {}", memo.stats());
+        len.ok_or_else(|| aoc_utils::Error::input("There is no way to the end"))
+    }
+}
+
+/// The longest ways found so far, keyed by the node and the set of nodes already visited.
+type Longest = Memo<(usize, u64), Option<usize>>;
+
+/// The longest way from `start` to `target` that avoids the nodes in `seen`, a bit per node.
+fn _longest_path_len(
+    graph: &Graph,
+    start: usize,
+    target: usize,
+    seen: u64,
+    memo: &mut Longest,
+) -> Option<usize> {
+    if start == target {
+        return Some(0);
+    }
+    memo.get_or_compute((start, seen), |memo| {
+        let seen = seen | 1 << start;
+        graph.junctions.edges[start]
+            .iter()
+            .filter(|(pos, _dist)| seen & 1 << pos == 0)
+            .filter_map(|(pos, dist)| {
+                Some(_longest_path_len(graph, *pos, target, seen, memo)? + dist)
+            })
+            .max()
+    })
+}
+
+impl From<u8> for Plot {
+    fn from(value: u8) -> Self {
+        use Direction::*;
+        use Plot::*;
+        match value {
+            b'.' => Path,
+            b'#' => Forest,
+            b'^' => Slope(North),
+            b'>' => Slope(East),
+            b'v' => Slope(South),
+            b'<' => Slope(West),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl std::fmt::Debug for Plot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Plot::Path => write!(f, "."),
+            Plot::Forest => write!(f, "#"),
+            Plot::Slope(dir) => write!(f, "{}", dir.arrow()),
+        }
+    }
+}
+
+aoc_utils::tests!(Day23, "inputs-23-test" => 94, "inputs-23-test" => 154);
@@ -9,7 +9,7 @@
       name = "${package}-src";
       src = sourceByRegex ../.. [
         "^.*Cargo.(toml|lock)$"
-        "^(aoc-utils|day-.*)(/src.*)?$"
+        "^(aoc|aoc-utils|day-.*)(/src.*)?$"
         "^inputs.*$"
       ];
       filter = cleanSourceFilter;
@@ -11,6 +11,10 @@ project_name=$1
 # Change to root directory
 pushd "$(git rev-parse --show-toplevel)"
 # Init new cargo project
-cargo init --bin "$project_name"
+cargo init --lib "$project_name"
 cargo add --package "$project_name" --path aoc-utils
-cp template/src/main.rs "$project_name/src/main.rs"
+cp template/src/lib.rs "$project_name/src/lib.rs"
+sed "s/day_xx/${project_name//-/_}/" template/src/main.rs >"$project_name/src/main.rs"
+# Make the new day available to the `aoc` runner
+cargo add --package aoc --path "$project_name"
+echo "Don't forget to add $project_name to the registry in aoc/src/registry.rs"
@@ -13,6 +13,6 @@ hyperfine \
   --warmup 3 \
   --setup "nix build .#day-$day" \
   --command-name "day-$day first" \
-  "./result/bin/day-$day inputs/inputs-$day first" \
+  "./result/bin/day-$day --quiet --input inputs/inputs-$day --part 1" \
   --command-name "day-$day second" \
-  "./result/bin/day-$day inputs/inputs-$day second"
+  "./result/bin/day-$day --quiet --input inputs/inputs-$day --part 2"
@@ -0,0 +1,21 @@
+use aoc_utils::Lines;
+
+pub struct DayXX;
+
+impl aoc_utils::Problem<Lines> for DayXX {
+    type Solution = usize;
+
+    fn solve_first(mut input: Lines) -> aoc_utils::Result<Self::Solution> {
+        todo!()
+    }
+
+    fn solve_second(mut input: Lines) -> aoc_utils::Result<Self::Solution> {
+        todo!()
+    }
+}
+
+aoc_utils::tests! {
+    DayXX,
+    first { example: "inputs-xx-test" => 0 },
+    second pending,
+}
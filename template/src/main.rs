@@ -1,17 +1 @@
-use aoc_utils::Lines;
-
-struct DayXX;
-
-impl aoc_utils::Problem<Lines> for DayXX {
-    type Solution = usize;
-
-    fn solve_first(mut input: Lines) -> aoc_utils::Result<Self::Solution> {
-        todo!()
-    }
-
-    fn solve_second(mut input: Lines) -> aoc_utils::Result<Self::Solution> {
-        todo!()
-    }
-}
-
-aoc_utils::main!(DayXX, "inputs-xx" => 0, "inputs-xx" => 0);
+aoc_utils::main!(day_xx::DayXX);